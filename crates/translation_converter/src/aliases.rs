@@ -0,0 +1,134 @@
+use std::collections::HashSet;
+use swc_ecma_ast::{
+    ArrowExpr, BindingIdent, BlockStmt, Decl, Expr, Function, Ident, ModuleItem, ObjectPatProp,
+    Pat, Stmt, VarDeclarator,
+};
+use swc_ecma_visit::{Visit, VisitMut, VisitMutWith, VisitWith};
+
+/// Removes the declarations of aliases that are no longer referenced once their translations are converted
+/// nested functions and blocks are left alone, their own aliases have already been removed
+/// aliases of aliases are removed along with them, ex: const ns = l.orders; const { status } = ns;
+///
+/// # Examples
+/// ```text
+/// // const { common } = l;
+/// // t(common.save);
+/// remove_unused(&mut block.stmts, &["common".to_string()]);
+/// // t("common:save");
+/// ```
+pub fn remove_unused<N>(body: &mut N, aliases: &[String])
+where
+    N: VisitWith<ReferenceCollector> + VisitMutWith<AliasRemover>,
+{
+    loop {
+        let mut collector = ReferenceCollector::default();
+        body.visit_with(&mut collector);
+
+        let unused: HashSet<String> = aliases
+            .iter()
+            .filter(|alias| !collector.references.contains(*alias))
+            .cloned()
+            .collect();
+
+        if unused.is_empty() {
+            return;
+        }
+
+        let mut remover = AliasRemover {
+            unused,
+            removed: false,
+        };
+        body.visit_mut_with(&mut remover);
+
+        // removing an alias may leave the alias it was declared from unused
+        if !remover.removed {
+            return;
+        }
+    }
+}
+
+/// Collects the identifiers referenced within a node, including the ones of nested functions
+#[derive(Default)]
+pub struct ReferenceCollector {
+    references: HashSet<String>,
+}
+
+impl Visit for ReferenceCollector {
+    fn visit_ident(&mut self, ident: &Ident) {
+        self.references.insert(ident.sym.to_string());
+    }
+
+    // const { common } = l; declares common, it doesn't reference it
+    fn visit_binding_ident(&mut self, _: &BindingIdent) {}
+}
+
+pub struct AliasRemover {
+    unused: HashSet<String>,
+    removed: bool,
+}
+
+impl AliasRemover {
+    // Removes the unused bindings of a pattern, returns whether the whole declarator can be removed
+    // const { common, checkout } = l; -> const { checkout } = l;
+    fn remove_bindings(&mut self, pat: &mut Pat) -> bool {
+        let removable = match pat {
+            Pat::Ident(ident) => self.unused.contains(&*ident.sym),
+            Pat::Object(object) => {
+                let props = object.props.len();
+
+                object.props.retain(|prop| match prop {
+                    ObjectPatProp::Assign(assign) => !self.unused.contains(&*assign.key.sym),
+                    ObjectPatProp::KeyValue(key_value) => match &*key_value.value {
+                        Pat::Ident(ident) => !self.unused.contains(&*ident.sym),
+                        _ => true,
+                    },
+                    ObjectPatProp::Rest(_) => true,
+                });
+
+                self.removed |= object.props.len() != props;
+                object.props.is_empty()
+            }
+            _ => false,
+        };
+
+        self.removed |= removable;
+        removable
+    }
+}
+
+impl VisitMut for AliasRemover {
+    fn visit_mut_var_declarators(&mut self, declarators: &mut Vec<VarDeclarator>) {
+        // aliases are always initialized with l or one of its members
+        declarators.retain_mut(|declarator| {
+            !matches!(
+                declarator.init.as_deref(),
+                Some(Expr::Ident(_) | Expr::Member(_))
+            ) || !self.remove_bindings(&mut declarator.name)
+        });
+    }
+
+    fn visit_mut_stmts(&mut self, stmts: &mut Vec<Stmt>) {
+        stmts.visit_mut_children_with(self);
+
+        stmts.retain(|stmt| !matches!(stmt, Stmt::Decl(Decl::Var(var)) if var.decls.is_empty()));
+    }
+
+    // exported aliases are referenced by other modules, only statements are looked at
+    fn visit_mut_module_items(&mut self, items: &mut Vec<ModuleItem>) {
+        for item in items.iter_mut() {
+            if let ModuleItem::Stmt(stmt) = item {
+                stmt.visit_mut_with(self);
+            }
+        }
+
+        items.retain(|item| {
+            !matches!(item, ModuleItem::Stmt(Stmt::Decl(Decl::Var(var))) if var.decls.is_empty())
+        });
+    }
+
+    fn visit_mut_function(&mut self, _: &mut Function) {}
+
+    fn visit_mut_block_stmt(&mut self, _: &mut BlockStmt) {}
+
+    fn visit_mut_arrow_expr(&mut self, _: &mut ArrowExpr) {}
+}
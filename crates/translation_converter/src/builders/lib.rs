@@ -0,0 +1,495 @@
+use std::collections::HashMap;
+use swc_core::{
+    common::{Span, SyntaxContext, DUMMY_SP},
+    ecma::ast::{
+        ArrayLit, ArrowExpr, BindingIdent, CallExpr, Callee, ComputedPropName, Decl, ExportDecl,
+        Expr, ExprOrSpread, Function, Ident, IdentName, ImportDecl, ImportDefaultSpecifier,
+        ImportNamedSpecifier, ImportPhase, ImportSpecifier, Lit, MemberExpr, MemberProp,
+        ModuleDecl, ModuleExportName, ModuleItem, Pat, Str, ThisExpr, Tpl, TplElement, VarDecl,
+        VarDeclKind, VarDeclarator,
+    },
+};
+
+use swc_ecma_visit::{VisitMut, VisitMutWith};
+
+use crate::{
+    builders::{serializers, utils},
+    config::USE_TRANSLATION,
+};
+
+use super::serializers::ExprWithComputed;
+
+/// Generates a Box<Expr> give a MemberExpr and Span
+/// root is the path the first identifier of member stands for when it isn't l
+/// ex: checkoutL.summary.total with ["checkout"], common.save with ["common"] when const { common } = l;
+/// translations under a path of remap are moved to their new path, ex: { "common.form": "forms" }
+///
+/// # Examples
+/// ```
+/// use std::collections::HashMap;
+/// use swc_core::common::DUMMY_SP;
+/// use translation_converter::builders::lib::{box_expr, static_key};
+/// # use swc_core::common::{sync::Lrc, FileName, SourceMap};
+/// # fn member(src: &str) -> swc_ecma_ast::MemberExpr {
+/// #     let cm: Lrc<SourceMap> = Default::default();
+/// #     let fm = cm.new_source_file(FileName::Anon.into(), src.to_string());
+/// #     let expr = swc_ecma_parser::parse_file_as_expr(
+/// #         &fm, Default::default(), Default::default(), None, &mut vec![],
+/// #     );
+/// #     expr.unwrap().expect_member()
+/// # }
+///
+/// let key = box_expr(&member("l.common.foobar"), DUMMY_SP, None, &HashMap::new()).unwrap();
+/// assert_eq!(static_key(&key).as_deref(), Some("common:foobar"));
+///
+/// let remap = HashMap::from([("common.form".to_string(), "forms".to_string())]);
+/// let key = box_expr(&member("l.common.form.submit"), DUMMY_SP, None, &remap).unwrap();
+/// assert_eq!(static_key(&key).as_deref(), Some("forms:submit"));
+///
+/// // const { common } = l; common.save
+/// let root = ["common".to_string()];
+/// let key = box_expr(&member("common.save"), DUMMY_SP, Some(&root), &HashMap::new()).unwrap();
+/// assert_eq!(static_key(&key).as_deref(), Some("common:save"));
+///
+/// assert!(box_expr(&member("data.currentPatient"), DUMMY_SP, None, &HashMap::new()).is_none());
+/// ```
+pub fn box_expr(
+    member: &MemberExpr,
+    span: Span,
+    root: Option<&[String]>,
+    remap: &HashMap<String, String>,
+) -> Option<Box<Expr>> {
+    // if member doesn't contain an l object no need to do anything
+    if root.is_none() && !utils::has_child_l(member) {
+        return None;
+    }
+
+    // Serializes all Ident in member into a single String l.common.foobar -> "common:foobar"
+    let mut exprs = serializers::member_expr(member, &mut vec![]);
+
+    // checkoutL.summary.total -> ["checkout", "summary", "total"]
+    if let Some(root) = root {
+        exprs.splice(
+            0..0,
+            root.iter().map(|segment| serializers::ident_expr(segment)),
+        );
+    }
+
+    // l.common.form.submit -> ["forms", "submit"] with { "common.form": "forms" }
+    if let Some((from, to)) = serializers::remap_rule(&exprs, remap) {
+        exprs = serializers::remap_identifiers(exprs, from, to);
+    }
+
+    // A translation needs at least a namespace and a key
+    // This means that the translation l.common is invalid
+    if exprs.len() < 2 {
+        return None;
+    }
+
+    // This Expr can then be inserted into the AST to complete the code transformation process
+    Some(key_expr(exprs, span))
+}
+
+/// Generates a Box<Expr> given the serialized identifiers of a translation
+/// ex: ["common", "foobar"] -> "common:foobar", ["common", bar] -> `common:${bar}`
+pub fn key_expr(exprs: Vec<ExprWithComputed>, span: Span) -> Box<Expr> {
+    // exprs contains a computed Ident we need to generate an Expr::Tpl
+    if exprs.iter().any(|expr| expr.computed) {
+        return Box::new(expr_tpl(exprs, span));
+    }
+
+    // translation_value does not contain an interpolated value so we generate a Expr::Lit
+    Box::new(expr_lit(exprs, span))
+}
+
+/// Generates the l member expression of a translation given its identifiers, the inverse of key_expr
+/// ex: ["common", "foobar"] -> l.common.foobar, ["common", bar] -> l.common[bar]
+pub fn translation_member(exprs: Vec<ExprWithComputed>, span: Span) -> Box<Expr> {
+    let l = Box::new(Expr::Ident(Ident::new(
+        "l".into(),
+        DUMMY_SP,
+        SyntaxContext::empty(),
+    )));
+
+    exprs.into_iter().fold(l, |obj, expr| {
+        let prop = match (expr.computed, *expr.expr) {
+            (false, Expr::Ident(ident)) => MemberProp::Ident(IdentName::new(ident.sym, DUMMY_SP)),
+            (_, expr) => MemberProp::Computed(ComputedPropName {
+                span: DUMMY_SP,
+                expr: Box::new(expr),
+            }),
+        };
+
+        Box::new(Expr::Member(MemberExpr { span, obj, prop }))
+    })
+}
+
+/// Returns the value of a converted translation when it has no interpolated values
+/// ex: "common:foobar" -> Some("common:foobar"), `common:foo.${bar}` -> None
+pub fn static_key(key: &Expr) -> Option<String> {
+    match key {
+        Expr::Lit(Lit::Str(str)) => Some(str.value.to_string_lossy().to_string()),
+        _ => None,
+    }
+}
+
+/// Generates the Box<Expr> of a static translation, ex: "common:foobar"
+pub fn static_key_expr(key: &str, span: Span) -> Box<Expr> {
+    Box::new(str_lit(key.to_string(), span))
+}
+
+/// Returns the part of a converted translation before its first interpolated value
+/// ex: "common:foobar" -> Some("common:foobar"), `common:foo.${bar}` -> Some("common:foo.")
+pub fn static_start(key: &Expr) -> Option<String> {
+    match key {
+        Expr::Lit(Lit::Str(str)) => Some(str.value.to_string_lossy().to_string()),
+        Expr::Tpl(tpl) => Some(tpl.quasis.first()?.raw.to_string()),
+        _ => None,
+    }
+}
+
+/// Returns the static namespace of a converted translation
+/// ex: "common:foobar" -> Some("common"), `common:foo.${bar}` -> Some("common"), `${ns}:foobar` -> None
+pub fn key_namespace(key: &Expr) -> Option<String> {
+    static_start(key)?
+        .split_once(':')
+        .map(|(namespace, _)| namespace.to_string())
+}
+
+/// Replaces the static prefix of a converted translation, returns false when the translation doesn't start with it
+/// ex: replace_key_prefix("checkout:summary.total", "checkout:summary.", "") -> "total"
+/// ex: replace_key_prefix(`common:summary.${key}`, "common:summary.", "common:") -> `common:${key}`
+pub fn replace_key_prefix(key: &mut Expr, prefix: &str, replacement: &str) -> bool {
+    match key {
+        Expr::Lit(Lit::Str(str)) => {
+            let value = str.value.to_string_lossy().to_string();
+
+            let Some(rest) = value.strip_prefix(prefix) else {
+                return false;
+            };
+
+            *key = str_lit(format!("{replacement}{rest}"), str.span);
+            true
+        }
+        Expr::Tpl(tpl) => {
+            let Some(quasi) = tpl.quasis.first_mut() else {
+                return false;
+            };
+
+            let Some(rest) = quasi.raw.strip_prefix(prefix) else {
+                return false;
+            };
+
+            let replaced = format!("{replacement}{rest}");
+
+            quasi.cooked = Some(replaced.clone().into());
+            quasi.raw = replaced.into();
+            true
+        }
+        _ => false,
+    }
+}
+
+/// Generates a call to the translate function given its path and the converted key
+///
+/// # Examples
+/// ```
+/// use swc_core::common::DUMMY_SP;
+/// use translation_converter::builders::{
+///     lib::{static_key_expr, translate_call},
+///     utils::callee_path,
+/// };
+///
+/// // i18n.t("common:foobar")
+/// let call = translate_call("i18n.t", static_key_expr("common:foobar", DUMMY_SP), DUMMY_SP);
+/// let call = call.as_call().unwrap();
+///
+/// assert_eq!(callee_path(&call.callee).as_deref(), Some("i18n.t"));
+/// assert_eq!(call.args.len(), 1);
+/// ```
+pub fn translate_call(callee: &str, key: Box<Expr>, span: Span) -> Box<Expr> {
+    Box::new(Expr::Call(CallExpr {
+        span,
+        ctxt: SyntaxContext::empty(),
+        callee: Callee::Expr(callee_expr(callee, span)),
+        args: vec![ExprOrSpread {
+            spread: None,
+            expr: key,
+        }],
+        type_args: None,
+    }))
+}
+
+/// Generates an import of the default export, or of the named export when specified, bound to local
+///
+/// # Examples
+/// ```
+/// use translation_converter::builders::lib::import_decl;
+///
+/// // import i18n from "i18next";
+/// let item = import_decl("i18n", "i18next", None);
+/// let import = item.as_module_decl().unwrap().as_import().unwrap();
+///
+/// assert_eq!(import.src.value, *"i18next");
+/// assert!(import.specifiers[0].is_default());
+///
+/// // import { t } from "i18next";
+/// let item = import_decl("t", "i18next", Some("t"));
+/// let import = item.as_module_decl().unwrap().as_import().unwrap();
+///
+/// assert_eq!(import.specifiers[0].local().sym, "t");
+/// assert!(import.specifiers[0].is_named());
+/// ```
+pub fn import_decl(local: &str, source: &str, name: Option<&str>) -> ModuleItem {
+    let local_ident = Ident::new(local.into(), DUMMY_SP, SyntaxContext::empty());
+
+    let specifier = match name {
+        None => ImportSpecifier::Default(ImportDefaultSpecifier {
+            span: DUMMY_SP,
+            local: local_ident,
+        }),
+        Some(name) => ImportSpecifier::Named(ImportNamedSpecifier {
+            span: DUMMY_SP,
+            local: local_ident,
+            // import { t as translate } when the export and the local binding differ
+            imported: (name != local).then(|| {
+                ModuleExportName::Ident(Ident::new(name.into(), DUMMY_SP, SyntaxContext::empty()))
+            }),
+            is_type_only: false,
+        }),
+    };
+
+    ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl {
+        span: DUMMY_SP,
+        specifiers: vec![specifier],
+        src: Box::new(Str {
+            span: DUMMY_SP,
+            value: source.into(),
+            raw: None,
+        }),
+        type_only: false,
+        with: None,
+        phase: ImportPhase::Evaluation,
+    }))
+}
+
+/// Generates the export listing the namespaces used by a module
+/// ex: export const __i18nNamespaces = ["common", "checkout"];
+pub fn namespaces_export(name: &str, namespaces: &[String]) -> ModuleItem {
+    ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+        span: DUMMY_SP,
+        decl: Decl::Var(Box::new(VarDecl {
+            span: DUMMY_SP,
+            ctxt: SyntaxContext::empty(),
+            kind: VarDeclKind::Const,
+            declare: false,
+            decls: vec![VarDeclarator {
+                span: DUMMY_SP,
+                name: Pat::Ident(BindingIdent {
+                    id: Ident::new(name.into(), DUMMY_SP, SyntaxContext::empty()),
+                    type_ann: None,
+                }),
+                init: Some(namespaces_array(namespaces)),
+                definite: false,
+            }],
+        })),
+    }))
+}
+
+/// Fills the bare useTranslation() calls of a function body with the namespaces it uses
+/// nested functions are left alone, their own body has already been filled
+///
+/// # Examples
+/// ```
+/// use translation_converter::builders::{lib::fill_use_translation, utils::namespaces_argument};
+/// # use swc_core::common::{sync::Lrc, FileName, SourceMap};
+/// # let cm: Lrc<SourceMap> = Default::default();
+/// # let fm = cm.new_source_file(FileName::Anon.into(), "useTranslation()".to_string());
+/// # let parsed = swc_ecma_parser::parse_file_as_expr(
+/// #     &fm, Default::default(), Default::default(), None, &mut vec![],
+/// # );
+///
+/// // useTranslation() -> useTranslation(["common", "checkout"])
+/// let mut call = parsed.unwrap();
+/// fill_use_translation(&mut call, &["common".to_string(), "checkout".to_string()]);
+///
+/// assert_eq!(
+///     namespaces_argument(call.as_call().unwrap().args.first()),
+///     ["common", "checkout"]
+/// );
+/// ```
+pub fn fill_use_translation<N>(body: &mut N, namespaces: &[String])
+where
+    N: for<'a> VisitMutWith<UseTranslationFiller<'a>>,
+{
+    body.visit_mut_with(&mut UseTranslationFiller { namespaces });
+}
+
+pub struct UseTranslationFiller<'a> {
+    namespaces: &'a [String],
+}
+
+impl VisitMut for UseTranslationFiller<'_> {
+    fn visit_mut_call_expr(&mut self, call_expr: &mut CallExpr) {
+        call_expr.visit_mut_children_with(self);
+
+        if call_expr.args.is_empty()
+            && utils::callee_path(&call_expr.callee).as_deref() == Some(USE_TRANSLATION)
+        {
+            call_expr.args.push(ExprOrSpread {
+                spread: None,
+                expr: namespaces_array(self.namespaces),
+            });
+        }
+    }
+
+    fn visit_mut_function(&mut self, _: &mut Function) {}
+
+    fn visit_mut_arrow_expr(&mut self, _: &mut ArrowExpr) {}
+}
+
+/// ex: ["common", "checkout"]
+fn namespaces_array(namespaces: &[String]) -> Box<Expr> {
+    let elems = namespaces
+        .iter()
+        .map(|namespace| {
+            Some(ExprOrSpread {
+                spread: None,
+                expr: Box::new(str_lit(namespace.to_string(), DUMMY_SP)),
+            })
+        })
+        .collect();
+
+    Box::new(Expr::Array(ArrayLit {
+        span: DUMMY_SP,
+        elems,
+    }))
+}
+
+/// Generates the Expr a dotted path refers to, ex: "this.props.t" -> this.props.t
+fn callee_expr(path: &str, span: Span) -> Box<Expr> {
+    let mut segments = path.split('.');
+
+    let root = match segments.next().unwrap_or_default() {
+        "this" => Expr::This(ThisExpr { span }),
+        ident => Expr::Ident(Ident::new(ident.into(), span, SyntaxContext::empty())),
+    };
+
+    segments.fold(Box::new(root), |obj, segment| {
+        Box::new(Expr::Member(MemberExpr {
+            span,
+            obj,
+            prop: MemberProp::Ident(IdentName::new(segment.into(), span)),
+        }))
+    })
+}
+
+/// Given identifiers like ["common", "foobar"] expr_lit will generate the Expr::Lit of "common:foobar"
+/// We can later inject it into the AST to replace the respective l.common...
+fn expr_lit(exprs: Vec<ExprWithComputed>, span: Span) -> Expr {
+    let translation_value = serializers::concatenate_identifiers(exprs);
+
+    str_lit(translation_value, span)
+}
+
+/// Generates the Expr::Lit of a translation key
+fn str_lit(translation_value: String, span: Span) -> Expr {
+    // Else condition where translation_value does not contain ${} interpolated values
+    // raw properties of a Str need to contain escaped quotations such that they are represented as such in the AST
+    // "\"common:foobar\"", this is why we are using r#, SUPER IMPORTANT!
+    let translation_raw = format!(r#""{}""#, translation_value);
+
+    let string_literal = Str {
+        raw: Some(translation_raw.into()),
+        value: translation_value.into(),
+        span,
+    };
+
+    Expr::Lit(Lit::Str(string_literal))
+}
+
+/// Given a String with interpolated values "common:foo${bar}" expr_tpl will generate an Expr::Tpl enum
+/// We can later inject it into the AST to replace the respective l.common.foo[bar]
+fn expr_tpl(exprs_with_computed: Vec<ExprWithComputed>, span: Span) -> Expr {
+    let mut quasis: Vec<TplElement> = vec![];
+    let mut quasis_group: String = "".to_string();
+    let mut exprs: Vec<Box<Expr>> = vec![];
+
+    for (i, expr_with_computed) in exprs_with_computed.iter().enumerate() {
+        let first_iteration = i == 0;
+        let last_iteration = i == exprs_with_computed.len() - 1;
+
+        // expr that needs to be added to exprs, ex: ${common}
+        if expr_with_computed.computed {
+            // if quasis_group is not empty it means that we have prevously collected quasis that needs to be committed to the AST
+            if !quasis_group.is_empty() {
+                quasis.push(TplElement {
+                    span,
+                    tail: i == exprs_with_computed.len() - 1, // if this is the last ident, this quasis needs "tail: true"
+                    cooked: Some(quasis_group.clone().into()),
+                    raw: quasis_group.clone().into(),
+                });
+
+                quasis_group = "".to_string();
+            }
+
+            // Each expression within a TemplateLiteral must follow with a . unless it's the namespace
+            if !last_iteration && !first_iteration {
+                quasis_group += ".";
+            }
+
+            if first_iteration {
+                // We need to add : as it follows all namespaces computed or not
+                quasis_group += ":";
+
+                // If the first element is computed, we must append an empty quasis
+                quasis.push(TplElement {
+                    span,
+                    tail: false,
+                    cooked: Some("".into()),
+                    raw: "".into(),
+                });
+            }
+
+            // For the above described example, expr would be "bar" after stripping ${}
+            exprs.push(expr_with_computed.expr.clone());
+
+            // The last entry within a TemplateLiteral is computed, we need to add an empty trailing quasis
+            if i == exprs_with_computed.len() - 1 {
+                quasis.push(TplElement {
+                    span,
+                    tail: true,
+                    cooked: Some("".into()),
+                    raw: "".into(),
+                });
+            }
+        } else {
+            // not computed ident
+            // so we can assume it's an ident
+            quasis_group += &expr_with_computed.expr.as_ident().unwrap().sym as &str;
+
+            if first_iteration {
+                // we can assume that this is the namespace that is being added
+                quasis_group += ":";
+            } else if !last_iteration {
+                quasis_group += ".";
+            }
+        }
+    }
+
+    // When the template literal ends with a quasis
+    if !quasis_group.is_empty() {
+        quasis.push(TplElement {
+            span,
+            tail: false,
+            cooked: Some(quasis_group.clone().into()),
+            raw: quasis_group.clone().into(),
+        });
+    }
+
+    Expr::Tpl(Tpl {
+        exprs,
+        quasis,
+        span,
+    })
+}
@@ -0,0 +1,356 @@
+use std::collections::HashMap;
+use swc_core::common::{SyntaxContext, DUMMY_SP};
+use swc_ecma_ast::{Expr, Ident, Lit, MemberExpr};
+
+#[derive(Clone)]
+pub struct ExprWithComputed {
+    pub expr: Box<Expr>,
+    pub computed: bool,
+}
+
+/// Recurses through a MemberExpression that contains an l translation object and collects all Identifiers
+///
+/// # Examples
+/// ```
+/// use translation_converter::builders::serializers::member_expr;
+/// # use swc_core::common::{sync::Lrc, FileName, SourceMap};
+/// # fn member(src: &str) -> swc_ecma_ast::MemberExpr {
+/// #     let cm: Lrc<SourceMap> = Default::default();
+/// #     let fm = cm.new_source_file(FileName::Anon.into(), src.to_string());
+/// #     let expr = swc_ecma_parser::parse_file_as_expr(
+/// #         &fm, Default::default(), Default::default(), None, &mut vec![],
+/// #     );
+/// #     expr.unwrap().expect_member()
+/// # }
+///
+/// let identifiers = member_expr(&member("l.common.foo[bar]"), &mut vec![]);
+/// let names: Vec<(String, bool)> = identifiers
+///     .iter()
+///     .map(|identifier| (identifier.expr.as_ident().unwrap().sym.to_string(), identifier.computed))
+///     .collect();
+///
+/// assert_eq!(
+///     names,
+///     [("common".into(), false), ("foo".into(), false), ("bar".into(), true)]
+/// );
+/// ```
+pub fn member_expr(
+    member: &MemberExpr,
+    exprs: &mut Vec<ExprWithComputed>,
+) -> Vec<ExprWithComputed> {
+    // Case where member_expr is a nested collection of MemberExpr
+    // We need to recursively continue down the AST collecting all Ident as we go
+    // ex: l.common.foobar
+    if member.obj.is_member() {
+        member_expr(member.obj.as_member().unwrap(), exprs);
+    }
+
+    // If prop is an Ident, add it to the list of exprs to convert into a StringLiteral
+    if member.prop.is_ident() {
+        let ident_name = member.prop.as_ident().unwrap();
+        let ident = Ident::new(
+            ident_name.sym.clone(),
+            ident_name.span,
+            SyntaxContext::empty(),
+        );
+        exprs.push(ExprWithComputed {
+            expr: Box::new(Expr::Ident(ident)),
+            computed: false,
+        });
+    // If prop is a computed expr we need to convert [bar] into ${bar} before adding to the list of identifiers
+    // ex: l.common.foo[bar]
+    } else if member.prop.is_computed() {
+        let computed = member.prop.as_computed().unwrap();
+
+        exprs.push(ExprWithComputed {
+            expr: computed.expr.clone(),
+            computed: true,
+        });
+    }
+
+    exprs.clone()
+}
+
+/// Finds the remap rule whose path the identifiers start with, the longest path wins
+///
+/// # Examples
+/// ```
+/// use std::collections::HashMap;
+/// use translation_converter::builders::serializers::{member_expr, remap_rule};
+/// # use swc_core::common::{sync::Lrc, FileName, SourceMap};
+/// # fn member(src: &str) -> swc_ecma_ast::MemberExpr {
+/// #     let cm: Lrc<SourceMap> = Default::default();
+/// #     let fm = cm.new_source_file(FileName::Anon.into(), src.to_string());
+/// #     let expr = swc_ecma_parser::parse_file_as_expr(
+/// #         &fm, Default::default(), Default::default(), None, &mut vec![],
+/// #     );
+/// #     expr.unwrap().expect_member()
+/// # }
+///
+/// let remap = HashMap::from([("common.form".to_string(), "forms".to_string())]);
+/// let identifiers = member_expr(&member("l.common.form.submit"), &mut vec![]);
+///
+/// assert_eq!(remap_rule(&identifiers, &remap), Some(("common.form", "forms")));
+/// ```
+pub fn remap_rule<'a>(
+    identifiers: &[ExprWithComputed],
+    remap: &'a HashMap<String, String>,
+) -> Option<(&'a str, &'a str)> {
+    remap
+        .iter()
+        .filter(|(from, _)| {
+            let segments: Vec<&str> = from.split('.').collect();
+
+            // computed identifiers are only known at runtime, they can't be remapped
+            segments.len() <= identifiers.len()
+                && segments
+                    .iter()
+                    .zip(identifiers)
+                    .all(|(segment, identifier)| {
+                        !identifier.computed
+                            && identifier
+                                .expr
+                                .as_ident()
+                                .is_some_and(|ident| &ident.sym as &str == *segment)
+                    })
+        })
+        .max_by_key(|(from, _)| from.split('.').count())
+        .map(|(from, to)| (from.as_str(), to.as_str()))
+}
+
+/// Replaces the leading identifiers matching from with the ones of to
+/// ex: ["common", "form", "submit"], "common.form", "forms" -> ["forms", "submit"]
+pub fn remap_identifiers(
+    identifiers: Vec<ExprWithComputed>,
+    from: &str,
+    to: &str,
+) -> Vec<ExprWithComputed> {
+    to.split('.')
+        .map(ident_expr)
+        .chain(identifiers.into_iter().skip(from.split('.').count()))
+        .collect()
+}
+
+/// ex: "checkout" -> ExprWithComputed { expr: checkout, computed: false }
+pub fn ident_expr(name: &str) -> ExprWithComputed {
+    ExprWithComputed {
+        expr: Box::new(Expr::Ident(Ident::new(
+            name.into(),
+            DUMMY_SP,
+            SyntaxContext::empty(),
+        ))),
+        computed: false,
+    }
+}
+
+/// Joins a list of identifiers with the specified delimiter and outputs a string
+/// Assumes that exprs contains only identifiers
+fn join_identifiers(exprs: &[ExprWithComputed], delimiter: &str) -> String {
+    exprs
+        .iter()
+        .map(|e| e.expr.as_ident().unwrap().sym.to_string())
+        .collect::<Vec<String>>()
+        .join(delimiter)
+}
+
+/// Concatenates a list of identifier values (String) into a single String
+///
+/// # Examples
+/// ```
+/// use translation_converter::builders::serializers::{concatenate_identifiers, ident_expr};
+///
+/// let identifiers = vec![ident_expr("common"), ident_expr("foobar")];
+/// assert_eq!(concatenate_identifiers(identifiers), "common:foobar");
+///
+/// let identifiers = vec![ident_expr("common"), ident_expr("foo1"), ident_expr("foo2")];
+/// assert_eq!(concatenate_identifiers(identifiers), "common:foo1.foo2");
+/// ```
+pub fn concatenate_identifiers(identifiers: Vec<ExprWithComputed>) -> String {
+    // If collected identifiers is size 2 or more, we can safely concatenate the contents of identifiers
+    // ex: ["common", "foo1", "foo2"]
+    if identifiers.len() >= 2 {
+        // The first element in our vector is the translation namespace, second element is the element following the namespace
+        // ex: ["common", "foo1", "foo2"] from l.common.foo1.foo2
+        // "common" and "foo1" need to be joined by a ":" where everything else (properties) is joined by a "."
+        // The following statement becomes
+        //  - namespace_group = ["common", "foo1"]
+        //  - properties = ["foo2"]
+        let (namespace_group, properties) = identifiers.split_at(2);
+
+        // Case where identifiers was just ["common", "foobar"] originally
+        // - namespace_group = ["common", "foobar"]
+        // - properties = []
+        if properties.is_empty() {
+            join_identifiers(namespace_group, ":")
+        } else {
+            // Case where identifiers was ["common", "foo1", "foo2", ...] originally
+            // - namespace_group = ["common", "foo1"]
+            // - properties = ["foo2", ...]
+            format!(
+                "{}.{}",
+                join_identifiers(namespace_group, ":"),
+                join_identifiers(properties, "."),
+            )
+        }
+    } else {
+        "".to_string()
+    }
+}
+
+/// Serializes a MemberExpression back into its source representation, used to annotate converted translations
+/// Computed properties that are not an identifier or a literal are represented as [...]
+///
+/// # Examples
+/// ```
+/// use translation_converter::builders::serializers::member_path;
+/// # use swc_core::common::{sync::Lrc, FileName, SourceMap};
+/// # fn member(src: &str) -> swc_ecma_ast::MemberExpr {
+/// #     let cm: Lrc<SourceMap> = Default::default();
+/// #     let fm = cm.new_source_file(FileName::Anon.into(), src.to_string());
+/// #     let expr = swc_ecma_parser::parse_file_as_expr(
+/// #         &fm, Default::default(), Default::default(), None, &mut vec![],
+/// #     );
+/// #     expr.unwrap().expect_member()
+/// # }
+///
+/// assert_eq!(member_path(&member("l.common.foo[bar]")), "l.common.foo[bar]");
+/// assert_eq!(member_path(&member("l.common[getKey()]")), "l.common[...]");
+/// ```
+pub fn member_path(member: &MemberExpr) -> String {
+    let obj = match &*member.obj {
+        Expr::Member(obj) => member_path(obj),
+        Expr::Ident(ident) => ident.sym.to_string(),
+        _ => "(...)".to_string(),
+    };
+
+    if let Some(ident) = member.prop.as_ident() {
+        return format!("{}.{}", obj, ident.sym);
+    }
+
+    let computed = match member.prop.as_computed().map(|computed| &*computed.expr) {
+        Some(Expr::Ident(ident)) => ident.sym.to_string(),
+        Some(Expr::Lit(Lit::Str(str))) => format!("{:?}", str.value.to_string_lossy()),
+        Some(Expr::Lit(Lit::Num(num))) => num.value.to_string(),
+        _ => "...".to_string(),
+    };
+
+    format!("{}[{}]", obj, computed)
+}
+
+/// Splits a converted key back into the identifiers of its translation, the inverse of concatenate_identifiers
+/// interpolated values need to stand for whole identifiers and static ones need to be valid identifiers
+/// returns the namespace apart as keys passed to a t function bound to a namespace may omit it
+///
+/// # Examples
+/// ```
+/// use translation_converter::builders::{lib::static_key_expr, serializers::key_identifiers};
+/// use swc_core::common::DUMMY_SP;
+///
+/// // "common:foo.bar" -> Some((Some(common), [foo, bar]))
+/// let (namespace, identifiers) = key_identifiers(&static_key_expr("common:foo.bar", DUMMY_SP)).unwrap();
+///
+/// assert_eq!(namespace.unwrap().expr.as_ident().unwrap().sym, "common");
+/// assert_eq!(identifiers.len(), 2);
+///
+/// // "total" -> Some((None, [total]))
+/// let (namespace, _) = key_identifiers(&static_key_expr("total", DUMMY_SP)).unwrap();
+///
+/// assert!(namespace.is_none());
+///
+/// // "foo-bar" can't be written as a property of l
+/// assert!(key_identifiers(&static_key_expr("common:foo-bar", DUMMY_SP)).is_none());
+/// ```
+pub fn key_identifiers(key: &Expr) -> Option<(Option<ExprWithComputed>, Vec<ExprWithComputed>)> {
+    let tokens = match key {
+        Expr::Lit(Lit::Str(str)) => key_tokens(&str.value.to_string_lossy()),
+        Expr::Tpl(tpl) => {
+            let mut tokens = vec![];
+
+            for (i, quasi) in tpl.quasis.iter().enumerate() {
+                tokens.extend(key_tokens(&quasi.raw));
+
+                if let Some(expr) = tpl.exprs.get(i) {
+                    tokens.push(KeyToken::Identifier(ExprWithComputed {
+                        expr: expr.clone(),
+                        computed: true,
+                    }));
+                }
+            }
+
+            tokens
+        }
+        _ => return None,
+    };
+
+    let mut namespace = None;
+    let mut identifiers = vec![];
+    let mut expects_identifier = true;
+
+    for token in tokens {
+        match token {
+            KeyToken::Identifier(identifier) if expects_identifier => {
+                identifiers.push(identifier);
+                expects_identifier = false;
+            }
+            // common:${foo}bar
+            KeyToken::Identifier(_) => return None,
+            KeyToken::Separator(_) if expects_identifier => return None,
+            KeyToken::Separator(':') if namespace.is_none() && identifiers.len() == 1 => {
+                namespace = identifiers.pop();
+                expects_identifier = true;
+            }
+            KeyToken::Separator('.') => expects_identifier = true,
+            KeyToken::Separator(_) | KeyToken::Invalid => return None,
+        }
+    }
+
+    if expects_identifier {
+        return None;
+    }
+
+    Some((namespace, identifiers))
+}
+
+enum KeyToken {
+    Identifier(ExprWithComputed),
+    Separator(char),
+    /// A word that can't be written as a property of l, ex: foo-bar
+    Invalid,
+}
+
+// "common:foo.bar" -> [common, :, foo, ., bar]
+fn key_tokens(key: &str) -> Vec<KeyToken> {
+    let mut tokens = vec![];
+    let mut word = String::new();
+
+    for char in key.chars() {
+        if char == ':' || char == '.' {
+            tokens.extend(word_token(&mut word));
+            tokens.push(KeyToken::Separator(char));
+        } else {
+            word.push(char);
+        }
+    }
+
+    tokens.extend(word_token(&mut word));
+    tokens
+}
+
+fn word_token(word: &mut String) -> Option<KeyToken> {
+    if word.is_empty() {
+        return None;
+    }
+
+    let word = std::mem::take(word);
+    let mut chars = word.chars();
+    let is_identifier = chars
+        .next()
+        .is_some_and(|char| char.is_alphabetic() || char == '_' || char == '$')
+        && chars.all(|char| char.is_alphanumeric() || char == '_' || char == '$');
+
+    if !is_identifier {
+        return Some(KeyToken::Invalid);
+    }
+
+    Some(KeyToken::Identifier(ident_expr(&word)))
+}
@@ -0,0 +1,387 @@
+use std::collections::HashMap;
+use swc_ecma_ast::{
+    CallExpr, Callee, Decl, Expr, ExprOrSpread, Ident, JSXElementName, JSXMemberExpr, JSXObject,
+    Lit, MemberExpr, Module, ModuleDecl, ModuleItem, ObjectPatProp, Pat, PropName, Stmt,
+};
+use swc_ecma_visit::{Visit, VisitWith};
+
+pub fn has_child_l(member_expr: &MemberExpr) -> bool {
+    if member_expr.obj.is_member() {
+        return has_child_l(member_expr.obj.as_member().unwrap());
+    } else if member_expr.obj.is_ident() {
+        let ident = member_expr.obj.as_ident().unwrap();
+
+        return &ident.sym as &str == "l";
+    }
+
+    false
+}
+
+/// Returns the identifier a chain of member expressions starts with
+/// ex: checkoutL.summary.total -> checkoutL, getL().common -> None
+pub fn root_ident(member_expr: &MemberExpr) -> Option<&Ident> {
+    match &*member_expr.obj {
+        Expr::Member(obj) => root_ident(obj),
+        Expr::Ident(ident) => Some(ident),
+        _ => None,
+    }
+}
+
+/// Serializes a callee made of identifiers into its dotted path
+/// ex: t -> "t", this.props.t -> "this.props.t", getT()(...) -> None
+pub fn callee_path(callee: &Callee) -> Option<String> {
+    match callee {
+        Callee::Expr(expr) => expr_path(expr),
+        _ => None,
+    }
+}
+
+fn expr_path(expr: &Expr) -> Option<String> {
+    match expr {
+        Expr::Ident(ident) => Some(ident.sym.to_string()),
+        Expr::This(_) => Some("this".to_string()),
+        Expr::Member(member) => {
+            let prop = member.prop.as_ident()?;
+            Some(format!("{}.{}", expr_path(&member.obj)?, prop.sym))
+        }
+        Expr::Paren(paren) => expr_path(&paren.expr),
+        _ => None,
+    }
+}
+
+/// Serializes the name of a JSX element into its dotted path
+/// ex: <Trans /> -> "Trans", <Foo.Trans /> -> "Foo.Trans"
+pub fn jsx_element_name(name: &JSXElementName) -> String {
+    match name {
+        JSXElementName::Ident(ident) => ident.sym.to_string(),
+        JSXElementName::JSXMemberExpr(member) => jsx_member_path(member),
+        JSXElementName::JSXNamespacedName(name) => format!("{}:{}", name.ns.sym, name.name.sym),
+    }
+}
+
+fn jsx_member_path(member: &JSXMemberExpr) -> String {
+    let obj = match &member.obj {
+        JSXObject::Ident(ident) => ident.sym.to_string(),
+        JSXObject::JSXMemberExpr(member) => jsx_member_path(member),
+    };
+
+    format!("{}.{}", obj, member.prop.sym)
+}
+
+/// Collects the identifiers the t function returned by useTranslation is bound to
+/// ex: const { t } = useTranslation(); -> ["t"]
+/// ex: const { t: translate } = useTranslation(); -> ["translate"]
+/// ex: const [t] = useTranslation(); -> ["t"]
+pub fn translate_function_bindings(pat: &Pat) -> Vec<String> {
+    match pat {
+        Pat::Object(object) => object
+            .props
+            .iter()
+            .filter_map(|prop| match prop {
+                ObjectPatProp::Assign(assign) if &assign.key.sym as &str == "t" => {
+                    Some(assign.key.sym.to_string())
+                }
+                ObjectPatProp::KeyValue(key_value) => match (&key_value.key, &*key_value.value) {
+                    (PropName::Ident(key), Pat::Ident(value)) if &key.sym as &str == "t" => {
+                        Some(value.sym.to_string())
+                    }
+                    _ => None,
+                },
+                _ => None,
+            })
+            .collect(),
+        Pat::Array(array) => match array.elems.first() {
+            Some(Some(Pat::Ident(ident))) => vec![ident.sym.to_string()],
+            _ => vec![],
+        },
+        _ => vec![],
+    }
+}
+
+/// Collects the local names of the imports whose source is mapped to a namespace
+/// ex: import checkoutL from "locales/en/checkout.json"; with { "locales/en/checkout.json": "checkout" } -> { "checkoutL": "checkout" }
+pub fn import_roots(module: &Module, sources: &HashMap<String, String>) -> HashMap<String, String> {
+    module
+        .body
+        .iter()
+        .filter_map(|item| match item {
+            ModuleItem::ModuleDecl(ModuleDecl::Import(import)) => Some(import),
+            _ => None,
+        })
+        .filter_map(|import| {
+            let namespace = sources.get(&*import.src.value.to_string_lossy())?;
+            Some((import, namespace))
+        })
+        .flat_map(|(import, namespace)| {
+            import
+                .specifiers
+                .iter()
+                // import checkoutL from "...", import * as checkoutL from "..."
+                .filter(|specifier| !specifier.is_named())
+                .map(|specifier| (specifier.local().sym.to_string(), namespace.clone()))
+        })
+        .collect()
+}
+
+/// Collects the identifiers a declaration of translations binds along with the path they add
+/// ex: const ns = l.checkout; -> [("ns", [])]
+/// ex: const { common, checkout: c } = l; -> [("common", ["common"]), ("c", ["checkout"])]
+pub fn alias_bindings(pat: &Pat) -> Vec<(String, Vec<String>)> {
+    match pat {
+        Pat::Ident(ident) => vec![(ident.sym.to_string(), vec![])],
+        Pat::Object(object) => object
+            .props
+            .iter()
+            .filter_map(|prop| match prop {
+                // const { common = fallback } = l; could refer to anything
+                ObjectPatProp::Assign(assign) if assign.value.is_none() => {
+                    Some((assign.key.sym.to_string(), vec![assign.key.sym.to_string()]))
+                }
+                ObjectPatProp::KeyValue(key_value) => match (&key_value.key, &*key_value.value) {
+                    (PropName::Ident(key), Pat::Ident(value)) => {
+                        Some((value.sym.to_string(), vec![key.sym.to_string()]))
+                    }
+                    _ => None,
+                },
+                _ => None,
+            })
+            .collect(),
+        _ => vec![],
+    }
+}
+
+/// Collects every identifier a pattern binds
+/// ex: function Foo(common, { items: [first], ...rest }) -> ["common", "first", "rest"]
+pub fn pat_bindings(pat: &Pat) -> Vec<String> {
+    match pat {
+        Pat::Ident(ident) => vec![ident.sym.to_string()],
+        Pat::Array(array) => array
+            .elems
+            .iter()
+            .flatten()
+            .flat_map(pat_bindings)
+            .collect(),
+        Pat::Object(object) => object
+            .props
+            .iter()
+            .flat_map(|prop| match prop {
+                ObjectPatProp::Assign(assign) => vec![assign.key.sym.to_string()],
+                ObjectPatProp::KeyValue(key_value) => pat_bindings(&key_value.value),
+                ObjectPatProp::Rest(rest) => pat_bindings(&rest.arg),
+            })
+            .collect(),
+        Pat::Rest(rest) => pat_bindings(&rest.arg),
+        Pat::Assign(assign) => pat_bindings(&assign.left),
+        Pat::Expr(_) | Pat::Invalid(_) => vec![],
+    }
+}
+
+/// Reads the namespaces loaded by useTranslation, withTranslation or getFixedT
+/// ex: useTranslation("checkout") -> ["checkout"]
+/// ex: useTranslation(["checkout", "common"]) -> ["checkout", "common"]
+/// ex: useTranslation(namespace) -> []
+pub fn namespaces_argument(arg: Option<&ExprOrSpread>) -> Vec<String> {
+    let Some(ExprOrSpread { spread: None, expr }) = arg else {
+        return vec![];
+    };
+
+    match &**expr {
+        Expr::Lit(Lit::Str(str)) => vec![str.value.to_string_lossy().to_string()],
+        Expr::Array(array) => array
+            .elems
+            .iter()
+            .map(|elem| match elem.as_ref().map(|elem| &*elem.expr) {
+                Some(Expr::Lit(Lit::Str(str))) => Some(str.value.to_string_lossy().to_string()),
+                _ => None,
+            })
+            // a single computed namespace makes the default namespace unknown
+            .collect::<Option<Vec<String>>>()
+            .unwrap_or_default(),
+        _ => vec![],
+    }
+}
+
+/// Reads the keyPrefix option given to useTranslation
+/// ex: useTranslation("checkout", { keyPrefix: "summary" }) -> Some("summary")
+pub fn key_prefix_option(arg: Option<&ExprOrSpread>) -> Option<String> {
+    let Expr::Object(object) = &*arg?.expr else {
+        return None;
+    };
+
+    object.props.iter().find_map(|prop| {
+        let key_value = prop.as_prop()?.as_key_value()?;
+
+        let is_key_prefix = match &key_value.key {
+            PropName::Ident(ident) => &ident.sym as &str == "keyPrefix",
+            PropName::Str(str) => str.value == *"keyPrefix",
+            _ => false,
+        };
+
+        if !is_key_prefix {
+            return None;
+        }
+
+        string_argument(&key_value.value)
+    })
+}
+
+/// Reads the keyPrefix given to getFixedT
+/// ex: i18n.getFixedT(null, "checkout", "summary") -> Some("summary")
+pub fn key_prefix_argument(arg: Option<&ExprOrSpread>) -> Option<String> {
+    string_argument(&arg?.expr)
+}
+
+fn string_argument(expr: &Expr) -> Option<String> {
+    match expr {
+        Expr::Lit(Lit::Str(str)) => Some(str.value.to_string_lossy().to_string()),
+        _ => None,
+    }
+}
+
+/// Collects the components wrapped in withTranslation along with the namespaces they are given
+/// ex: export default withTranslation("checkout")(Checkout); -> { "Checkout": ["checkout"] }
+pub fn with_translation_components<N: VisitWith<WithTranslationCollector>>(
+    node: &N,
+) -> HashMap<String, Vec<String>> {
+    let mut collector = WithTranslationCollector::default();
+    node.visit_with(&mut collector);
+
+    collector.components
+}
+
+#[derive(Default)]
+pub struct WithTranslationCollector {
+    components: HashMap<String, Vec<String>>,
+}
+
+impl Visit for WithTranslationCollector {
+    fn visit_call_expr(&mut self, call_expr: &CallExpr) {
+        call_expr.visit_children_with(self);
+
+        // withTranslation("checkout") is the callee of the call wrapping the component
+        let Callee::Expr(callee) = &call_expr.callee else {
+            return;
+        };
+
+        let Expr::Call(with_translation) = &**callee else {
+            return;
+        };
+
+        if callee_path(&with_translation.callee).as_deref() != Some("withTranslation") {
+            return;
+        }
+
+        if let Some(Expr::Ident(component)) = call_expr.args.first().map(|arg| &*arg.expr) {
+            self.components.insert(
+                component.sym.to_string(),
+                namespaces_argument(with_translation.args.first()),
+            );
+        }
+    }
+}
+
+/// Number of directives the module starts with, imports are inserted after them so they stay in the prologue
+/// ex: "use client"; "use strict"; import i18n from "i18next"; -> 2
+pub fn prologue_len(module: &Module) -> usize {
+    module
+        .body
+        .iter()
+        .take_while(|item| {
+            matches!(
+                item,
+                ModuleItem::Stmt(Stmt::Expr(stmt)) if matches!(&*stmt.expr, Expr::Lit(Lit::Str(_)))
+            )
+        })
+        .count()
+}
+
+/// Whether name is imported or declared at the top level of module
+pub fn module_binds(module: &Module, name: &str) -> bool {
+    module.body.iter().any(|item| match item {
+        ModuleItem::ModuleDecl(ModuleDecl::Import(import)) => import
+            .specifiers
+            .iter()
+            .any(|specifier| &specifier.local().sym as &str == name),
+        ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export)) => decl_binds(&export.decl, name),
+        ModuleItem::Stmt(Stmt::Decl(decl)) => decl_binds(decl, name),
+        _ => false,
+    })
+}
+
+fn decl_binds(decl: &Decl, name: &str) -> bool {
+    match decl {
+        Decl::Fn(func) => &func.ident.sym as &str == name,
+        Decl::Class(class) => &class.ident.sym as &str == name,
+        Decl::Var(var) => var.decls.iter().any(|declarator| match &declarator.name {
+            Pat::Ident(ident) => &ident.sym as &str == name,
+            _ => false,
+        }),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::bool_assert_comparison, clippy::useless_conversion)]
+mod tests {
+    mod has_child_l {
+        use swc_core::common::{BytePos, Span, SyntaxContext};
+        use swc_ecma_ast::{Ident, MemberExpr, MemberProp};
+
+        use crate::builders::utils::has_child_l;
+
+        #[test]
+        fn returns_true_when_starts_with_l() {
+            // l.common
+            let span = Span::new(BytePos(0), BytePos(0)).into();
+            let translation_namespace =
+                Ident::new("common".into(), span, SyntaxContext::empty()).into();
+
+            let translation_member_expr = MemberExpr {
+                obj: Box::new(Ident::new("l".into(), span, SyntaxContext::empty()).into()),
+                prop: MemberProp::Ident(translation_namespace),
+                span,
+            };
+
+            assert_eq!(has_child_l(&translation_member_expr), true);
+        }
+
+        #[test]
+        fn returns_false_when_does_not_contain_l() {
+            // data.currentPatient
+            let span = Span::new(BytePos(0), BytePos(0)).into();
+            let current_patient_ident =
+                Ident::new("currentPatient".into(), span, SyntaxContext::empty()).into();
+
+            let data_member_expr = MemberExpr {
+                obj: Box::new(Ident::new("data".into(), span, SyntaxContext::empty()).into()),
+                prop: MemberProp::Ident(current_patient_ident),
+                span,
+            };
+
+            assert_eq!(has_child_l(&data_member_expr), false);
+        }
+
+        #[test]
+        fn returns_false_when_it_does_not_start_with_l() {
+            // common.l.foobar
+            let span = Span::new(BytePos(0), BytePos(0)).into();
+            let foobar_ident = Ident::new("foobar".into(), span, SyntaxContext::empty()).into();
+            let l_ident = Ident::new("l".into(), span, SyntaxContext::empty()).into();
+            let common_ident = Ident::new("common".into(), span, SyntaxContext::empty()).into();
+
+            let nested_member_expr = MemberExpr {
+                obj: Box::new(common_ident),
+                prop: MemberProp::Ident(l_ident),
+                span,
+            };
+
+            let member_expr = MemberExpr {
+                obj: Box::new(nested_member_expr.into()),
+                prop: MemberProp::Ident(foobar_ident),
+                span,
+            };
+
+            assert_eq!(has_child_l(&member_expr), false);
+        }
+    }
+}
@@ -0,0 +1,436 @@
+use globset::{Glob, GlobBuilder, GlobSet, GlobSetBuilder};
+use serde::Deserialize;
+use serde_json::Value;
+use std::{collections::HashMap, path::Path};
+
+/// Options passed to the plugin from the SWC configuration
+///
+/// # Examples
+/// ```js
+/// plugins: [
+///   ["@fullscript/swc-plugin-translation-converter", {
+///     include: ["src/**"],
+///     exclude: ["**/*.stories.tsx", "**/__tests__/**"],
+///     // sections merged over the options above depending on the SWC env
+///     env: {
+///       development: { annotate: true },
+///       production: { strict: true },
+///     },
+///   }],
+/// ]
+/// ```
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct Config {
+    /// Whether every translation is converted, only the ones passed to known translation sinks, or none in lint mode
+    pub mode: Mode,
+    /// Functions and JSX attributes expecting a key, only used in targeted mode
+    pub sinks: Sinks,
+    /// What to do with translations rendered as JSX children, <p>{l.common.foobar}</p>
+    pub jsx_children: JsxChildren,
+    /// Function translations are passed to when they need to be translated in place
+    pub translate: Translate,
+    /// Positions where translations are displayed rather than passed to a translate function
+    pub auto_wrap: AutoWrap,
+    /// Export listing the namespaces used by each module, for loaders to preload them
+    pub namespace_manifest: Option<NamespaceManifest>,
+    /// Fills bare useTranslation() calls with the namespaces of the translations converted in the same function
+    /// ex: const { t } = useTranslation(); -> const { t } = useTranslation(["common", "checkout"]);
+    pub preload_namespaces: bool,
+    /// Identifiers other than l referring to the translations of a single namespace
+    /// ex: { "checkoutL": "checkout" } -> checkoutL.summary.total is converted into "checkout:summary.total"
+    pub roots: HashMap<String, String>,
+    /// Import sources of the translations of a single namespace, their default or namespace import is used as a root
+    /// ex: { "locales/en/checkout.json": "checkout" } -> import checkoutL from "locales/en/checkout.json";
+    pub root_imports: HashMap<String, String>,
+    /// Paths of translations that moved, keyed by their old path
+    /// ex: { "common.form": "forms" } -> l.common.form.submit is converted into "forms:submit"
+    pub remap: HashMap<String, String>,
+    /// Reports translations converted through remap so they can be updated to their new path
+    pub warn_remapped: bool,
+    /// Renamed keys keyed by their old key, removed keys are mapped to null
+    /// ex: { "common:save": "common:submit", "common:legacy": null }
+    pub deprecated_keys: HashMap<String, Option<String>>,
+    /// Directory of the resources of a language, with a JSON file per namespace, relative to the working directory
+    /// ex: "public/locales/en" -> public/locales/en/common.json, public/locales/en/checkout.json
+    pub resources: Option<String>,
+    /// Glob patterns of the files to convert, every file is converted when empty
+    pub include: Vec<String>,
+    /// Glob patterns of the files to leave untouched, takes precedence over include
+    pub exclude: Vec<String>,
+    /// Reports invalid translations as errors rather than warnings
+    pub strict: bool,
+    /// Adds the original translation as a comment next to the converted key
+    /// ex: t("common:foobar" /* l.common.foobar */)
+    pub annotate: bool,
+    // include and exclude compiled once when parsing rather than for every file
+    #[serde(skip)]
+    include_set: GlobSet,
+    #[serde(skip)]
+    exclude_set: GlobSet,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum Mode {
+    /// Every translation is converted
+    #[default]
+    All,
+    /// Only translations passed to sinks are converted, any other translation is reported and left untouched
+    Targeted,
+    /// Nothing is converted, keys written as strings in sinks are reported so l is used instead
+    /// ex: t("common:save") -> use l.common.save instead
+    Lint,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct Sinks {
+    pub callees: Vec<CalleeSink>,
+    pub jsx: Vec<JsxSink>,
+}
+
+impl Default for Sinks {
+    fn default() -> Self {
+        let callee = |callee: &str| CalleeSink {
+            callee: callee.to_string(),
+            arguments: first_argument(),
+        };
+
+        Sinks {
+            callees: vec![
+                callee("t"),
+                callee("i18n.t"),
+                callee("i18next.t"),
+                callee("this.props.t"),
+                callee(USE_TRANSLATION),
+            ],
+            jsx: vec![JsxSink {
+                component: "Trans".to_string(),
+                attribute: "i18nKey".to_string(),
+            }],
+        }
+    }
+}
+
+/// Callee pattern matching the t functions returned by useTranslation
+/// ex: const { t: translate } = useTranslation(); translate(l.common.foobar);
+pub const USE_TRANSLATION: &str = "useTranslation";
+
+/// ex: { callee: "i18n.t", arguments: [0] } -> i18n.t(l.common.foobar)
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CalleeSink {
+    /// Dotted path of the function, or useTranslation for the functions it returns
+    pub callee: String,
+    /// Indices of the arguments expecting a key
+    #[serde(default = "first_argument")]
+    pub arguments: Vec<usize>,
+}
+
+fn first_argument() -> Vec<usize> {
+    vec![0]
+}
+
+/// ex: { component: "Trans", attribute: "i18nKey" } -> <Trans i18nKey={l.common.foobar} />
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JsxSink {
+    pub component: String,
+    pub attribute: String,
+}
+
+/// Rendering a key as is would display "common:foobar" on the page
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum JsxChildren {
+    /// <p>{t("common:foobar")}</p>
+    #[default]
+    Translate,
+    /// Leaves the translation untouched and fails the build
+    Error,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct Translate {
+    /// Path of the function called with the key, ex: t, i18n.t, this.props.t
+    pub callee: String,
+    /// Import injected when a translation is wrapped and the callee is not imported yet
+    pub import: Option<TranslateImport>,
+}
+
+impl Default for Translate {
+    fn default() -> Self {
+        Translate {
+            callee: "t".to_string(),
+            import: None,
+        }
+    }
+}
+
+/// ex: { source: "i18next" } -> import i18n from "i18next";
+/// ex: { source: "i18next", name: "t" } -> import { t } from "i18next";
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TranslateImport {
+    pub source: String,
+    /// Named export to import, the default export is imported when missing
+    pub name: Option<String>,
+}
+
+/// Translations in these positions are wrapped in a translate call rather than replaced with a bare key
+/// ex: <Button label={l.common.save} /> -> <Button label={i18n.t("common:save")} />
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct AutoWrap {
+    /// Names of the JSX attributes displaying their value, ex: label, title, placeholder
+    pub attributes: Vec<String>,
+    pub positions: Vec<Position>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum Position {
+    /// return l.common.foobar;
+    Return,
+    /// isFoo ? l.common.foo : l.common.bar
+    Conditional,
+    /// { label: l.common.foobar }
+    Property,
+}
+
+/// ex: { name: "__i18nNamespaces" } -> export const __i18nNamespaces = ["common", "checkout"];
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct NamespaceManifest {
+    pub name: String,
+    pub computed: ComputedNamespaces,
+}
+
+impl Default for NamespaceManifest {
+    fn default() -> Self {
+        NamespaceManifest {
+            name: "__i18nNamespaces".to_string(),
+            computed: ComputedNamespaces::default(),
+        }
+    }
+}
+
+/// Namespaces of translations such as l[namespace].foobar are only known at runtime
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum ComputedNamespaces {
+    /// Reported as they can't be listed in the manifest
+    #[default]
+    Report,
+    /// Listed as "*" so loaders can preload every namespace
+    Wildcard,
+}
+
+impl Config {
+    /// Parses the JSON configuration given to the plugin
+    /// the section of env matching the SWC env (development, production, test...) is merged over the base options
+    ///
+    /// # Panics
+    /// When the configuration is invalid, the plugin can't run without it
+    pub fn parse(config: &str, env: Option<&str>) -> Config {
+        Config::try_parse(config, env)
+            .unwrap_or_else(|error| panic!("swc-plugin-translation-converter: {error}"))
+    }
+
+    /// Same as parse, returns invalid JSON, options and glob patterns as an error
+    pub fn try_parse(config: &str, env: Option<&str>) -> Result<Config, String> {
+        let mut config: Value = serde_json::from_str(config)
+            .map_err(|error| format!("invalid plugin configuration: {error}"))?;
+
+        if let Some(base) = config.as_object_mut() {
+            let sections = base.remove("env");
+
+            if let Some(section) = env.and_then(|env| sections?.get(env).cloned()) {
+                merge(&mut config, section);
+            }
+        }
+
+        let mut config: Config = serde_json::from_value(config)
+            .map_err(|error| format!("invalid plugin configuration: {error}"))?;
+
+        config.include_set = glob_set(&config.include)?;
+        config.exclude_set = glob_set(&config.exclude)?;
+
+        Ok(config)
+    }
+
+    /// Whether the file should be converted according to the include and exclude patterns
+    /// filename is expected to be relative to the project root, ex: src/components/Foo.tsx
+    pub fn should_transform(&self, filename: &str) -> bool {
+        if !self.include.is_empty() && !self.include_set.is_match(filename) {
+            return false;
+        }
+
+        !self.exclude_set.is_match(filename)
+    }
+}
+
+/// Deep merges overrides into base, objects are merged key by key while any other value is replaced
+fn merge(base: &mut Value, overrides: Value) {
+    match (base, overrides) {
+        (Value::Object(base), Value::Object(overrides)) => {
+            for (key, value) in overrides {
+                merge(base.entry(key).or_insert(Value::Null), value);
+            }
+        }
+        (base, overrides) => *base = overrides,
+    }
+}
+
+/// Strips the working directory from the filename given by SWC so it can be matched against patterns
+/// ex: /home/app/src/Foo.tsx -> src/Foo.tsx
+pub fn relative_filename<'a>(filename: &'a str, cwd: Option<&str>) -> &'a str {
+    cwd.and_then(|cwd| Path::new(filename).strip_prefix(cwd).ok())
+        .and_then(|path| path.to_str())
+        .unwrap_or(filename)
+}
+
+/// Compiles a list of glob patterns into a GlobSet
+/// * only matches within a directory, ** is needed to match across directories
+fn glob_set(patterns: &[String]) -> Result<GlobSet, String> {
+    let mut builder = GlobSetBuilder::new();
+
+    for pattern in patterns {
+        builder.add(glob(pattern)?);
+    }
+
+    builder
+        .build()
+        .map_err(|error| format!("invalid glob patterns: {error}"))
+}
+
+fn glob(pattern: &str) -> Result<Glob, String> {
+    GlobBuilder::new(pattern)
+        .literal_separator(true)
+        .build()
+        .map_err(|error| format!("invalid glob {pattern}: {error}"))
+}
+
+#[cfg(test)]
+mod tests {
+    mod should_transform {
+        use crate::config::Config;
+
+        #[test]
+        fn returns_true_without_patterns() {
+            let config = Config::parse("{}", None);
+
+            assert!(config.should_transform("src/components/Foo.tsx"));
+        }
+
+        #[test]
+        fn returns_false_when_not_included() {
+            let config = Config::parse(r#"{ "include": ["src/**"] }"#, None);
+
+            assert!(config.should_transform("src/components/Foo.tsx"));
+            assert!(!config.should_transform("storybook/Foo.tsx"));
+        }
+
+        #[test]
+        fn returns_false_when_excluded() {
+            let config = Config::parse(
+                r#"{ "include": ["src/**"], "exclude": ["**/*.stories.tsx", "**/__tests__/**"] }"#,
+                None,
+            );
+
+            assert!(config.should_transform("src/components/Foo.tsx"));
+            assert!(!config.should_transform("src/components/Foo.stories.tsx"));
+            assert!(!config.should_transform("src/components/__tests__/Foo.test.tsx"));
+        }
+
+        #[test]
+        fn single_star_does_not_match_across_directories() {
+            let config = Config::parse(r#"{ "exclude": ["*.stories.tsx"] }"#, None);
+
+            assert!(!config.should_transform("Foo.stories.tsx"));
+            assert!(config.should_transform("src/Foo.stories.tsx"));
+        }
+    }
+
+    mod parse {
+        use crate::config::Config;
+
+        const CONFIG: &str = r#"{
+            "exclude": ["**/*.stories.tsx"],
+            "env": {
+                "development": { "annotate": true },
+                "production": { "strict": true, "exclude": [] }
+            }
+        }"#;
+
+        #[test]
+        fn uses_base_options_without_env() {
+            let config = Config::parse(CONFIG, None);
+
+            assert!(!config.strict);
+            assert!(!config.annotate);
+            assert_eq!(config.exclude, vec!["**/*.stories.tsx"]);
+        }
+
+        #[test]
+        fn merges_matching_env_section_over_base_options() {
+            let config = Config::parse(CONFIG, Some("production"));
+
+            assert!(config.strict);
+            assert!(!config.annotate);
+            assert!(config.exclude.is_empty());
+        }
+
+        #[test]
+        fn ignores_sections_of_other_envs() {
+            let config = Config::parse(CONFIG, Some("test"));
+
+            assert!(!config.strict);
+            assert!(!config.annotate);
+        }
+
+        #[test]
+        fn parses_removed_deprecated_keys_as_none() {
+            let config = Config::parse(
+                r#"{ "deprecatedKeys": { "common:save": "common:submit", "common:legacy": null } }"#,
+                None,
+            );
+
+            assert_eq!(
+                config.deprecated_keys.get("common:save"),
+                Some(&Some("common:submit".to_string()))
+            );
+            assert_eq!(config.deprecated_keys.get("common:legacy"), Some(&None));
+        }
+
+        #[test]
+        fn returns_invalid_glob_patterns_as_an_error() {
+            let error = Config::try_parse(r#"{ "exclude": ["src/[a-"] }"#, None).unwrap_err();
+
+            assert!(error.starts_with("invalid glob src/[a-"), "{error}");
+        }
+    }
+
+    mod relative_filename {
+        use crate::config::relative_filename;
+
+        #[test]
+        fn strips_the_working_directory() {
+            assert_eq!(
+                relative_filename("/home/app/src/Foo.tsx", Some("/home/app")),
+                "src/Foo.tsx"
+            );
+        }
+
+        #[test]
+        fn returns_filename_when_outside_of_working_directory() {
+            assert_eq!(
+                relative_filename("/tmp/Foo.tsx", Some("/home/app")),
+                "/tmp/Foo.tsx"
+            );
+            assert_eq!(relative_filename("src/Foo.tsx", None), "src/Foo.tsx");
+        }
+    }
+}
@@ -0,0 +1,17 @@
+use swc_core::common::{errors::HANDLER, Span};
+
+pub enum Severity {
+    Warning,
+    Error,
+}
+
+/// Reports a diagnostic for the given span through the SWC handler
+/// Errors fail the compilation while warnings are only printed
+pub fn emit(severity: Severity, span: Span, message: &str) {
+    let message = format!("swc-plugin-translation-converter: {message}");
+
+    HANDLER.with(|handler| match severity {
+        Severity::Warning => handler.struct_span_warn(span, &message).emit(),
+        Severity::Error => handler.struct_span_err(span, &message).emit(),
+    });
+}
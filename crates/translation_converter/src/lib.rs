@@ -0,0 +1,1231 @@
+use config::{ComputedNamespaces, Config, JsxChildren, Mode, Position};
+use diagnostics::Severity;
+use resources::Resources;
+use scope::{Scopes, TranslateFunction};
+use std::{collections::HashMap, mem, path::Path, sync::Arc};
+use swc_core::common::{
+    comments::{Comment, CommentKind, Comments},
+    BytePos, Span, Spanned, DUMMY_SP,
+};
+use swc_ecma_ast::{
+    ArrayLit, ArrowExpr, BinaryOp, BlockStmt, CallExpr, CatchClause, ClassDecl, CondExpr, Expr,
+    ExprOrSpread, FnDecl, Function, JSXAttr, JSXAttrName, JSXAttrOrSpread, JSXAttrValue,
+    JSXElementChild, JSXExpr, JSXExprContainer, JSXOpeningElement, KeyValueProp, Lit, MemberExpr,
+    Module, ModuleItem, Pass, Pat, Program, PropOrSpread, ReturnStmt, Stmt, VarDecl, VarDeclKind,
+    VarDeclarator,
+};
+use swc_ecma_visit::{visit_mut_pass, VisitMut, VisitMutWith, VisitWith};
+
+pub mod builders {
+    pub mod lib;
+    pub mod serializers;
+    pub mod utils;
+}
+mod aliases;
+pub mod config;
+mod diagnostics;
+mod lint;
+pub mod pragmas;
+pub mod resources;
+pub mod scope;
+
+/// Creates the pass converting the translations of a program
+///
+/// # Examples
+/// ```
+/// use translation_converter::{config::Config, translation_converter};
+/// # use swc_core::common::{comments::SingleThreadedComments, sync::Lrc, FileName, SourceMap};
+/// # let cm: Lrc<SourceMap> = Default::default();
+/// # let fm = cm.new_source_file(FileName::Anon.into(), "t(l.common.foobar);".to_string());
+/// # let comments = SingleThreadedComments::default();
+/// # let program = swc_ecma_parser::parse_file_as_program(
+/// #     &fm, Default::default(), Default::default(), Some(&comments), &mut vec![],
+/// # )
+/// # .unwrap();
+///
+/// // t(l.common.foobar); -> t("common:foobar");
+/// let program = program.apply(translation_converter(comments, Config::default()));
+/// ```
+pub fn translation_converter<C: Comments>(comments: C, config: Config) -> impl Pass {
+    visit_mut_pass(TranslationConverter::new(comments, config))
+}
+
+/// Serializes a translation into the key it is converted into, computed properties are kept as ${...}
+///
+/// # Examples
+/// ```
+/// use translation_converter::translation_key;
+/// # use swc_core::common::{sync::Lrc, FileName, SourceMap};
+/// # fn member(src: &str) -> swc_ecma_ast::MemberExpr {
+/// #     let cm: Lrc<SourceMap> = Default::default();
+/// #     let fm = cm.new_source_file(FileName::Anon.into(), src.to_string());
+/// #     let expr = swc_ecma_parser::parse_file_as_expr(
+/// #         &fm, Default::default(), Default::default(), None, &mut vec![],
+/// #     );
+/// #     expr.unwrap().expect_member()
+/// # }
+///
+/// assert_eq!(translation_key(&member("l.common.foobar")).as_deref(), Some("common:foobar"));
+/// assert_eq!(translation_key(&member("l.common.foo[bar]")).as_deref(), Some("common:foo.${bar}"));
+/// assert_eq!(translation_key(&member("data.currentPatient")), None);
+/// ```
+pub fn translation_key(member: &MemberExpr) -> Option<String> {
+    let key = builders::lib::box_expr(member, DUMMY_SP, None, &HashMap::new())?;
+
+    key_string(&key)
+}
+
+// "common:foobar" -> common:foobar, `common:foo.${bar}` -> common:foo.${bar}
+pub(crate) fn key_string(key: &Expr) -> Option<String> {
+    match key {
+        Expr::Lit(Lit::Str(str)) => Some(str.value.to_string_lossy().to_string()),
+        Expr::Tpl(tpl) => {
+            let mut key = String::new();
+
+            for (i, quasi) in tpl.quasis.iter().enumerate() {
+                key.push_str(&quasi.raw);
+
+                if let Some(expr) = tpl.exprs.get(i) {
+                    let interpolated = match &**expr {
+                        Expr::Ident(ident) => ident.sym.to_string(),
+                        _ => "...".to_string(),
+                    };
+
+                    key.push_str(&format!("${{{interpolated}}}"));
+                }
+            }
+
+            Some(key)
+        }
+        _ => None,
+    }
+}
+
+/// A translation converted by the converter along with the key it resolved to
+pub struct Translation {
+    /// Key once aliases, roots, remapped paths and deprecated keys are resolved, ex: "common:foo.${bar}"
+    /// the namespace and key prefix of the t function it is passed to are kept
+    pub key: String,
+    pub span: Span,
+}
+
+/// Converts the l translations of a program into react-i18next keys
+///
+/// # Examples
+/// ```
+/// use swc_ecma_visit::VisitMutWith;
+/// use translation_converter::{config::Config, TranslationConverter};
+/// # use swc_core::common::{comments::SingleThreadedComments, sync::Lrc, FileName, SourceMap};
+/// # let cm: Lrc<SourceMap> = Default::default();
+/// # let fm = cm.new_source_file(FileName::Anon.into(), "t(l.common.foobar);".to_string());
+/// # let comments = SingleThreadedComments::default();
+/// # let mut program = swc_ecma_parser::parse_file_as_program(
+/// #     &fm, Default::default(), Default::default(), Some(&comments), &mut vec![],
+/// # )
+/// # .unwrap();
+///
+/// let config = Config::parse(r#"{ "mode": "targeted" }"#, None);
+///
+/// program.visit_mut_with(&mut TranslationConverter::new(comments, config));
+/// ```
+pub struct TranslationConverter<C: Comments> {
+    comments: C,
+    config: Config,
+    // whether a translation was wrapped in a translate call, the translate import may need to be injected
+    wrapped: bool,
+    // whether the node being visited is passed to a sink, ex: the first argument of t(...)
+    in_sink: bool,
+    // whether the node being visited is any argument of a call to a sink, ex: the options of t(..., { label })
+    in_sink_call: bool,
+    // whether the module binds the translate callee itself, ex: import i18n from "i18next";
+    translate_bound: bool,
+    scopes: Scopes,
+    // translations converted so far, see TranslationConverter::translations
+    translations: Vec<Translation>,
+    // namespaces of the converted translations in order of appearance, listed by the namespace manifest
+    namespaces: Vec<String>,
+    // namespaces of the translations converted within each function enclosing the node being visited
+    function_namespaces: Vec<Vec<String>>,
+    // identifiers referring to the translations of a single namespace, from the roots and root_imports options
+    roots: HashMap<String, String>,
+    // translations keys are checked against, from the resources option, loaded once per process
+    resources: Option<Arc<Resources>>,
+}
+
+impl<C: Comments> TranslationConverter<C> {
+    pub fn new(comments: C, config: Config) -> Self {
+        TranslationConverter {
+            comments,
+            config,
+            wrapped: false,
+            in_sink: false,
+            in_sink_call: false,
+            translate_bound: false,
+            scopes: Scopes::default(),
+            translations: vec![],
+            namespaces: vec![],
+            function_namespaces: vec![],
+            roots: HashMap::new(),
+            resources: None,
+        }
+    }
+
+    /// Translations converted by the visited programs, in order of appearance
+    pub fn translations(&self) -> &[Translation] {
+        &self.translations
+    }
+
+    // Path of the translations the first identifier of the member stands for when it isn't l
+    // ex: checkoutL.summary.total -> ["checkout"], common.save -> ["common"] when const { common } = l;
+    fn root_path(&self, member: &MemberExpr) -> Option<Vec<String>> {
+        let root = builders::utils::root_ident(member)?;
+
+        if let Some(path) = self.scopes.alias(&root.sym) {
+            return Some(path.to_vec());
+        }
+
+        self.roots
+            .get(&*root.sym)
+            .map(|namespace| vec![namespace.clone()])
+    }
+
+    // Whether the member refers to translations, ex: l.common.foobar, checkoutL.summary.total
+    fn is_translation(&self, member: &MemberExpr) -> bool {
+        builders::utils::has_child_l(member) || self.root_path(member).is_some()
+    }
+
+    // Path of the translations an expression refers to
+    // ex: l -> [], l.checkout -> ["checkout"], common.form -> ["common", "form"] when const { common } = l;
+    fn translation_path(&self, expr: &Expr) -> Option<Vec<String>> {
+        match expr {
+            Expr::Ident(ident) => match self.scopes.alias(&ident.sym) {
+                Some(path) => Some(path.to_vec()),
+                None => (&*ident.sym == "l").then(Vec::new),
+            },
+            Expr::Member(member) => {
+                let mut path = self.translation_path(&member.obj)?;
+                path.push(member.prop.as_ident()?.sym.to_string());
+                Some(path)
+            }
+            _ => None,
+        }
+    }
+
+    // Binds the aliases declared by a declarator of translations, returns false when it declares something else
+    // const { common } = l; const ns = l.checkout;
+    fn bind_aliases(&mut self, declarator: &VarDeclarator) -> bool {
+        let Some(path) = declarator
+            .init
+            .as_deref()
+            .and_then(|init| self.translation_path(init))
+        else {
+            return false;
+        };
+
+        let bindings = builders::utils::alias_bindings(&declarator.name);
+
+        if bindings.is_empty() {
+            return false;
+        }
+
+        for (name, suffix) in bindings {
+            self.scopes
+                .bind_alias(&name, path.iter().cloned().chain(suffix).collect());
+        }
+
+        true
+    }
+
+    // Identifiers bound by anything other than translations hide the aliases of the same name
+    fn shadow_aliases(&mut self, pat: &Pat) {
+        for name in builders::utils::pat_bindings(pat) {
+            self.scopes.shadow_alias(&name);
+        }
+    }
+
+    // In targeted mode only translations passed to sinks are converted
+    fn converts(&self) -> bool {
+        self.config.mode == Mode::All || self.in_sink
+    }
+
+    // Converts l.common.foobar into "common:foobar" unless it is preceded by an ignore pragma
+    // t(/* translation-converter-ignore */ l.common.foobar);
+    fn convert(&mut self, member: &MemberExpr, span: Span) -> Option<Box<Expr>> {
+        // translations outside of sinks are reported by visit_mut_expr
+        if !self.converts() {
+            return None;
+        }
+
+        if pragmas::has_leading_pragma(&self.comments, member.span.lo, pragmas::IGNORE) {
+            return None;
+        }
+
+        let expr = builders::lib::box_expr(
+            member,
+            span,
+            self.root_path(member).as_deref(),
+            &self.config.remap,
+        );
+
+        // l.common on its own is the namespace object, there is no key to convert it into
+        if expr.is_none() && self.is_translation(member) {
+            self.report(
+                member.span,
+                &format!(
+                    "{} is not a translation, expected a namespace followed by a key",
+                    builders::serializers::member_path(member)
+                ),
+            );
+        }
+
+        let mut expr = expr?;
+
+        if self.config.warn_remapped {
+            self.report_remapped(member);
+        }
+
+        self.replace_deprecated_key(&mut expr, member, span);
+
+        if let Some(key) = key_string(&expr) {
+            self.translations.push(Translation {
+                key,
+                span: member.span,
+            });
+        }
+
+        self.collect_namespace(&expr, member);
+
+        // t("common:foobar" /* l.common.foobar */);
+        if self.config.annotate {
+            self.comments.add_trailing(
+                span.hi,
+                Comment {
+                    kind: CommentKind::Block,
+                    span: DUMMY_SP,
+                    text: format!(" {} ", builders::serializers::member_path(member)).into(),
+                },
+            );
+        }
+
+        Some(expr)
+    }
+
+    // Visits a JSX expression container displaying its value
+    // rendered is true for JSX children, which are converted according to the jsx_children option
+    fn visit_mut_displayed_jsx_expr_container(
+        &mut self,
+        jsx_expr_cont: &mut JSXExprContainer,
+        rendered: bool,
+    ) {
+        if self.is_ignored(jsx_expr_cont) {
+            return;
+        }
+
+        if let JSXExpr::Expr(expr) = &mut jsx_expr_cont.expr {
+            self.visit_mut_displayed_expr(expr, rendered);
+        }
+    }
+
+    // Wraps the translations displayed by an expression, conditional and logical expressions display their operands
+    // <p>{isFoo ? l.common.foo : l.common.bar}</p> -> <p>{isFoo ? t("common:foo") : t("common:bar")}</p>
+    fn visit_mut_displayed_expr(&mut self, expr: &mut Box<Expr>, rendered: bool) {
+        if pragmas::has_leading_pragma(&self.comments, expr.span().lo, pragmas::IGNORE) {
+            return;
+        }
+
+        match &mut **expr {
+            Expr::Cond(cond_expr) => {
+                cond_expr.test.visit_mut_with(self);
+                self.visit_mut_displayed_expr(&mut cond_expr.cons, rendered);
+                self.visit_mut_displayed_expr(&mut cond_expr.alt, rendered);
+            }
+            // the left operand of && is only displayed when it is falsy, ex: {isFoo && l.common.foo}
+            Expr::Bin(bin_expr) if bin_expr.op == BinaryOp::LogicalAnd => {
+                bin_expr.left.visit_mut_with(self);
+                self.visit_mut_displayed_expr(&mut bin_expr.right, rendered);
+            }
+            Expr::Bin(bin_expr)
+                if matches!(
+                    bin_expr.op,
+                    BinaryOp::LogicalOr | BinaryOp::NullishCoalescing
+                ) =>
+            {
+                self.visit_mut_displayed_expr(&mut bin_expr.left, rendered);
+                self.visit_mut_displayed_expr(&mut bin_expr.right, rendered);
+            }
+            Expr::Paren(paren_expr) => {
+                self.visit_mut_displayed_expr(&mut paren_expr.expr, rendered)
+            }
+            Expr::Member(_) => {
+                // required to ensure that other visit_mut fn are called for children, ex: l.common[isFoo ? l.common.foo : l.common.bar]
+                expr.visit_mut_with(self);
+
+                // translations outside of sinks have already been reported
+                if !self.converts() {
+                    return;
+                }
+
+                let span = expr.span();
+                let member_expr = expr.as_member().unwrap();
+
+                if rendered && self.config.jsx_children == JsxChildren::Error {
+                    if self.is_translation(member_expr) {
+                        diagnostics::emit(
+                            Severity::Error,
+                            member_expr.span,
+                            &format!(
+                                "{} is rendered as is, it needs to be passed to a translate function",
+                                builders::serializers::member_path(member_expr)
+                            ),
+                        );
+                    }
+
+                    return;
+                }
+
+                if let Some(box_expr) = self.convert(member_expr, span) {
+                    *expr = self.wrap(box_expr, span);
+                }
+            }
+            _ => expr.visit_mut_with(self),
+        }
+    }
+
+    // Keys in the default namespace of the t function they are passed to don't need their namespace
+    // const { t } = useTranslation(["checkout", "common"]);
+    // t(l.checkout.total) -> t("total"), t(l.common.save) -> t("common:save")
+    // the key prefix of the t function is stripped as well
+    // const { t } = useTranslation("checkout", { keyPrefix: "summary" });
+    // t(l.checkout.summary.total) -> t("total")
+    fn shorten_key(
+        &self,
+        key: &mut Expr,
+        member: &MemberExpr,
+        callee: &str,
+        translate_function: &TranslateFunction,
+    ) {
+        let Some(default_namespace) = translate_function.namespaces.first() else {
+            return;
+        };
+
+        // `${namespace}:foobar` can't be checked against the loaded namespaces
+        let Some(namespace) = builders::lib::key_namespace(key) else {
+            return;
+        };
+
+        if !translate_function.namespaces.contains(&namespace) {
+            return self.report(
+                member.span,
+                &format!(
+                    "{} is in the {} namespace which is never loaded by {}, loaded namespaces: {}",
+                    builders::serializers::member_path(member),
+                    namespace,
+                    callee,
+                    translate_function.namespaces.join(", ")
+                ),
+            );
+        }
+
+        let replacement = if &namespace == default_namespace {
+            String::new()
+        } else {
+            format!("{namespace}:")
+        };
+
+        let prefix = match &translate_function.key_prefix {
+            Some(key_prefix) => format!("{namespace}:{key_prefix}."),
+            None => format!("{namespace}:"),
+        };
+
+        if !builders::lib::replace_key_prefix(key, &prefix, &replacement) {
+            self.report(
+                member.span,
+                &format!(
+                    "{} is outside of the {} key prefix of {}, {} prepends it to every key",
+                    builders::serializers::member_path(member),
+                    translate_function.key_prefix.as_deref().unwrap_or_default(),
+                    callee,
+                    callee
+                ),
+            );
+        }
+    }
+
+    // l.common.form.submit is deprecated, common.form has moved to forms
+    // remapped translations are converted correctly, they are always reported as warnings even in strict mode
+    fn report_remapped(&self, member: &MemberExpr) {
+        let identifiers = builders::serializers::member_expr(member, &mut vec![]);
+
+        if let Some((from, to)) =
+            builders::serializers::remap_rule(&identifiers, &self.config.remap)
+        {
+            diagnostics::emit(
+                Severity::Warning,
+                member.span,
+                &format!(
+                    "{} is deprecated, {} has moved to {}",
+                    builders::serializers::member_path(member),
+                    from,
+                    to
+                ),
+            );
+        }
+    }
+
+    // Renamed keys are replaced with their new key and reported as warnings, removed keys fail the build
+    // { "common:save": "common:submit" }: t(l.common.save) -> t("common:submit")
+    fn replace_deprecated_key(&self, key: &mut Box<Expr>, member: &MemberExpr, span: Span) {
+        let Some(old_key) = builders::lib::static_key(key) else {
+            return self.report_deprecated_parent(key, member);
+        };
+
+        match self.config.deprecated_keys.get(&old_key) {
+            None => (),
+            Some(Some(new_key)) => {
+                diagnostics::emit(
+                    Severity::Warning,
+                    member.span,
+                    &format!(
+                        "{} is deprecated, {} has been renamed to {}",
+                        builders::serializers::member_path(member),
+                        old_key,
+                        new_key
+                    ),
+                );
+
+                *key = builders::lib::static_key_expr(new_key, span);
+            }
+            Some(None) => diagnostics::emit(
+                Severity::Error,
+                member.span,
+                &format!(
+                    "{} has been removed, {} no longer exists",
+                    builders::serializers::member_path(member),
+                    old_key
+                ),
+            ),
+        }
+    }
+
+    // Keys with interpolated values can't be replaced, they are reported when they are under a deprecated key
+    // { "common:save": "common:submit" }: t(l.common.save[bar]) -> t(`common:save.${bar}`)
+    fn report_deprecated_parent(&self, key: &Expr, member: &MemberExpr) {
+        let Some(static_start) = builders::lib::static_start(key) else {
+            return;
+        };
+
+        let Some((old_key, new_key)) = self
+            .config
+            .deprecated_keys
+            .iter()
+            .filter(|(old_key, _)| static_start.starts_with(&format!("{old_key}.")))
+            .max_by_key(|(old_key, _)| old_key.len())
+        else {
+            return;
+        };
+
+        let status = match new_key {
+            Some(new_key) => format!("has been renamed to {new_key}"),
+            None => "has been removed".to_string(),
+        };
+
+        diagnostics::emit(
+            Severity::Warning,
+            member.span,
+            &format!(
+                "{} is under {} which {}, keys with interpolated values are not replaced",
+                builders::serializers::member_path(member),
+                old_key,
+                status
+            ),
+        );
+    }
+
+    // Records the namespace of a converted translation for the enclosing function and the namespace manifest
+    // l[namespace].foobar can't be listed, it is either reported or listed as "*"
+    fn collect_namespace(&mut self, key: &Expr, member: &MemberExpr) {
+        let key_namespace = builders::lib::key_namespace(key);
+
+        if let (Some(namespace), Some(namespaces)) =
+            (&key_namespace, self.function_namespaces.last_mut())
+        {
+            if !namespaces.contains(namespace) {
+                namespaces.push(namespace.clone());
+            }
+        }
+
+        let Some(manifest) = &self.config.namespace_manifest else {
+            return;
+        };
+
+        let namespace = match (key_namespace, &manifest.computed) {
+            (Some(namespace), _) => namespace,
+            (None, ComputedNamespaces::Wildcard) => "*".to_string(),
+            (None, ComputedNamespaces::Report) => {
+                return self.report(
+                    member.span,
+                    &format!(
+                        "{} has a computed namespace, it can't be listed in {}",
+                        builders::serializers::member_path(member),
+                        manifest.name
+                    ),
+                );
+            }
+        };
+
+        if !self.namespaces.contains(&namespace) {
+            self.namespaces.push(namespace);
+        }
+    }
+
+    // Called once a function has been visited with the namespaces used within its body
+    // they are used by the enclosing function as well, ex: items.map((item) => t(l.checkout.item))
+    fn leave_function<N>(&mut self, body: &mut N)
+    where
+        N: for<'a> VisitMutWith<builders::lib::UseTranslationFiller<'a>>,
+    {
+        let namespaces = self.function_namespaces.pop().unwrap_or_default();
+
+        if self.config.preload_namespaces && !namespaces.is_empty() {
+            builders::lib::fill_use_translation(body, &namespaces);
+        }
+
+        if let Some(parent) = self.function_namespaces.last_mut() {
+            for namespace in namespaces {
+                if !parent.contains(&namespace) {
+                    parent.push(namespace);
+                }
+            }
+        }
+    }
+
+    // Converts the translations of an array of fallback keys, the last key is used when none of the others exist
+    // t([l.errors.specific, l.errors.generic]) -> t(["errors:specific", "errors:generic"])
+    fn convert_fallback_keys(
+        &mut self,
+        array: &mut ArrayLit,
+        translate_function: Option<(&str, &TranslateFunction)>,
+    ) {
+        let last = array.elems.len().saturating_sub(1);
+
+        for (i, elem) in array.elems.iter_mut().enumerate() {
+            let Some(ExprOrSpread { spread: None, expr }) = elem else {
+                continue;
+            };
+
+            let Expr::Member(member) = &**expr else {
+                continue;
+            };
+
+            let Some(mut key) = self.convert(member, member.span) else {
+                continue;
+            };
+
+            if i == last {
+                self.check_fallback_key(&key, member);
+            }
+
+            if let Some((callee, translate_function)) = translate_function {
+                self.shorten_key(&mut key, member, callee, translate_function);
+            }
+
+            *expr = key;
+        }
+    }
+
+    // The last fallback key is displayed when none of the others exist, it needs to exist itself
+    fn check_fallback_key(&self, key: &Expr, member: &MemberExpr) {
+        let (Some(resources), Some(key)) = (&self.resources, builders::lib::static_key(key)) else {
+            return;
+        };
+
+        if !resources.has_key(&key) {
+            self.report(
+                member.span,
+                &format!(
+                    "{} is the last fallback key but {} does not exist in the resources",
+                    builders::serializers::member_path(member),
+                    key
+                ),
+            );
+        }
+    }
+
+    // Wraps a converted translation in a call to the translate function
+    // "common:foobar" -> t("common:foobar")
+    fn wrap(&mut self, key: Box<Expr>, span: Span) -> Box<Expr> {
+        self.wrapped = true;
+
+        // the call would throw a ReferenceError, the translate function is neither bound nor imported
+        // any binding of its root counts, ex: function Foo({ t }) {...}, const { i18n } = useTranslation();
+        let callee = &self.config.translate.callee;
+        let root = callee.split('.').next().unwrap_or(callee);
+
+        if self.config.translate.import.is_none()
+            && !self.translate_bound
+            && !self.scopes.is_translate_function(callee)
+            && !self.scopes.is_bound(root)
+        {
+            diagnostics::emit(
+                Severity::Error,
+                span,
+                &format!(
+                    "{callee} is not in scope, bind it with useTranslation or set translate.import so it is imported"
+                ),
+            );
+        }
+
+        builders::lib::translate_call(&self.config.translate.callee, key, span)
+    }
+
+    // Converts the translation and wraps it when the position is configured to be auto wrapped
+    fn convert_at(
+        &mut self,
+        position: Position,
+        member: &MemberExpr,
+        span: Span,
+    ) -> Option<Box<Expr>> {
+        let key = self.convert(member, span)?;
+
+        // the sink translates the key itself, ex: t(isFoo ? l.common.foo : l.common.bar)
+        // as well as the translations given as its options, ex: t(l.common.total, { label: l.common.label })
+        let translated = match position {
+            Position::Conditional | Position::Property => self.in_sink || self.in_sink_call,
+            Position::Return => false,
+        };
+
+        if !translated && self.config.auto_wrap.positions.contains(&position) {
+            return Some(self.wrap(key, span));
+        }
+
+        Some(key)
+    }
+
+    // Comments right after { are attached as trailing comments of the { token
+    // <Trans i18nKey={/* translation-converter-ignore */ l.common.foobar} />
+    fn is_ignored(&self, jsx_expr_cont: &JSXExprContainer) -> bool {
+        let after_brace = jsx_expr_cont.span.lo + BytePos(1);
+
+        pragmas::has_trailing_pragma(&self.comments, after_brace, pragmas::IGNORE)
+    }
+
+    // Invalid translations fail the build in strict mode, otherwise they are only reported as warnings
+    fn report(&self, span: Span, message: &str) {
+        let severity = if self.config.strict {
+            Severity::Error
+        } else {
+            Severity::Warning
+        };
+
+        diagnostics::emit(severity, span, message);
+    }
+}
+
+impl<C: Comments> VisitMut for TranslationConverter<C> {
+    // A file starting with // translation-converter-disable is left untouched
+    fn visit_mut_program(&mut self, program: &mut Program) {
+        // Leading comments of a file are attached to its first token
+        let first_item_lo = match program {
+            Program::Module(module) => module.body.first().map(|item| item.span().lo),
+            Program::Script(script) => script.body.first().map(|stmt| stmt.span().lo),
+        };
+
+        let disabled = [Some(program.span().lo), first_item_lo]
+            .into_iter()
+            .flatten()
+            .any(|pos| pragmas::has_leading_pragma(&self.comments, pos, pragmas::DISABLE));
+
+        if disabled {
+            return;
+        }
+
+        let components = builders::utils::with_translation_components(program);
+
+        // Lint mode only reports string keys, the program is left untouched
+        if self.config.mode == Mode::Lint {
+            let mut linter = lint::Linter::new(
+                &self.comments,
+                &self.config,
+                Scopes::with_components(components),
+            );
+
+            return program.visit_with(&mut linter);
+        }
+
+        self.scopes = Scopes::with_components(components);
+
+        // unreadable or invalid resources fail the build, fallback keys are not checked
+        if let Some(dir) = &self.config.resources {
+            match Resources::cached(Path::new(dir)) {
+                Ok(resources) => self.resources = Some(resources),
+                Err(error) => diagnostics::emit(Severity::Error, DUMMY_SP, &error),
+            }
+        }
+
+        // import checkoutL from "locales/en/checkout.json";
+        self.roots = self.config.roots.clone();
+
+        if let Program::Module(module) = program {
+            self.roots.extend(builders::utils::import_roots(
+                module,
+                &self.config.root_imports,
+            ));
+
+            // i18n.t -> i18n
+            self.translate_bound = builders::utils::module_binds(
+                module,
+                self.config
+                    .translate
+                    .callee
+                    .split('.')
+                    .next()
+                    .unwrap_or_default(),
+            );
+        }
+
+        program.visit_mut_children_with(self);
+    }
+
+    // Wrapped translations need the translate function to be imported
+    // import i18n from "i18next";
+    // The namespaces used by the module are exported for loaders to preload them
+    // export const __i18nNamespaces = ["common", "checkout"];
+    fn visit_mut_module(&mut self, module: &mut Module) {
+        module.visit_mut_children_with(self);
+
+        aliases::remove_unused(&mut module.body, &self.scopes.declared_aliases());
+
+        // modules loading no namespace are left without a manifest
+        match &self.config.namespace_manifest {
+            Some(manifest) if !self.namespaces.is_empty() => {
+                module.body.push(builders::lib::namespaces_export(
+                    &manifest.name,
+                    &self.namespaces,
+                ));
+            }
+            _ => (),
+        }
+
+        let Some(import) = &self.config.translate.import else {
+            return;
+        };
+
+        // i18n.t -> i18n, this.props.t can't be imported
+        let local = self
+            .config
+            .translate
+            .callee
+            .split('.')
+            .next()
+            .unwrap_or_default();
+
+        if !self.wrapped || local == "this" || builders::utils::module_binds(module, local) {
+            return;
+        }
+
+        // "use client"; needs to stay first for the module to be a client module
+        module.body.insert(
+            builders::utils::prologue_len(module),
+            builders::lib::import_decl(local, &import.source, import.name.as_deref()),
+        );
+    }
+
+    // import/export statements are not Stmt, they need to be checked for pragmas on their own
+    // translation-converter-disable-next-line
+    // export const label = l.common.foobar;
+    fn visit_mut_module_item(&mut self, module_item: &mut ModuleItem) {
+        if pragmas::has_leading_pragma(
+            &self.comments,
+            module_item.span().lo,
+            pragmas::DISABLE_NEXT_LINE,
+        ) {
+            return;
+        }
+
+        module_item.visit_mut_children_with(self);
+    }
+
+    // translation-converter-disable-next-line
+    // return l.common.foobar;
+    fn visit_mut_stmt(&mut self, stmt: &mut Stmt) {
+        if pragmas::has_leading_pragma(&self.comments, stmt.span().lo, pragmas::DISABLE_NEXT_LINE) {
+            return;
+        }
+
+        stmt.visit_mut_children_with(self);
+    }
+
+    // translation-converter-disable-next-line
+    // { label: l.common.foobar }
+    fn visit_mut_prop_or_spread(&mut self, prop: &mut PropOrSpread) {
+        if pragmas::has_leading_pragma(&self.comments, prop.span().lo, pragmas::DISABLE_NEXT_LINE) {
+            return;
+        }
+
+        prop.visit_mut_children_with(self);
+    }
+
+    // /* translation-converter-ignore */ isFoo ? l.common.foo : l.common.bar
+    fn visit_mut_expr(&mut self, expr: &mut Expr) {
+        if pragmas::has_leading_pragma(&self.comments, expr.span().lo, pragmas::IGNORE) {
+            return;
+        }
+
+        // In targeted mode translations that are not passed to a sink are reported and left untouched
+        // ex: mobileHeaderContent(faChevronDown, l.common.ShowOrderSummary)
+        if let Expr::Member(member_expr) = expr {
+            if !self.converts() && self.is_translation(member_expr) {
+                self.report(
+                    member_expr.span,
+                    &format!(
+                        "{} is not passed to a translation function, it is left untouched",
+                        builders::serializers::member_path(member_expr)
+                    ),
+                );
+                return;
+            }
+        }
+
+        expr.visit_mut_children_with(self);
+    }
+
+    // Bindings of a function are only visible within its body
+    // parameters named like an alias hide it, ex: function Foo(common) { common.save }
+    fn visit_mut_function(&mut self, function: &mut Function) {
+        self.scopes.push();
+        self.function_namespaces.push(vec![]);
+
+        for param in function.params.iter() {
+            self.shadow_aliases(&param.pat);
+        }
+
+        function.visit_mut_children_with(self);
+        self.scopes.pop();
+        self.leave_function(&mut function.body);
+    }
+
+    fn visit_mut_arrow_expr(&mut self, arrow_expr: &mut ArrowExpr) {
+        self.scopes.push();
+        self.function_namespaces.push(vec![]);
+
+        for param in arrow_expr.params.iter() {
+            self.shadow_aliases(param);
+        }
+
+        arrow_expr.visit_mut_children_with(self);
+        self.scopes.pop();
+        self.leave_function(&mut arrow_expr.body);
+    }
+
+    // Bindings of let and const are only visible within their block, including the body of a function
+    fn visit_mut_block_stmt(&mut self, block_stmt: &mut BlockStmt) {
+        self.scopes.push();
+        block_stmt.visit_mut_children_with(self);
+        aliases::remove_unused(&mut block_stmt.stmts, &self.scopes.declared_aliases());
+        self.scopes.pop();
+    }
+
+    // catch (common) {...}
+    fn visit_mut_catch_clause(&mut self, catch_clause: &mut CatchClause) {
+        self.scopes.push();
+
+        if let Some(param) = &catch_clause.param {
+            self.shadow_aliases(param);
+        }
+
+        catch_clause.visit_mut_children_with(self);
+        self.scopes.pop();
+    }
+
+    // const { common } = l; is left untouched, the references to common are converted instead
+    fn visit_mut_var_decl(&mut self, var_decl: &mut VarDecl) {
+        for declarator in var_decl.decls.iter_mut() {
+            // bindings of let and var can be reassigned, they may not refer to translations anymore
+            if var_decl.kind == VarDeclKind::Const && self.bind_aliases(declarator) {
+                continue;
+            }
+
+            // const common = getCommon(); hides the alias of the same name
+            self.shadow_aliases(&declarator.name);
+
+            declarator.visit_mut_with(self);
+        }
+    }
+
+    // function Checkout({ t }) {...}
+    // export default withTranslation("checkout")(Checkout);
+    fn visit_mut_fn_decl(&mut self, fn_decl: &mut FnDecl) {
+        self.scopes.shadow_alias(&fn_decl.ident.sym);
+
+        let entered = self.scopes.enter_component(&fn_decl.ident.sym);
+        fn_decl.visit_mut_children_with(self);
+
+        if entered {
+            self.scopes.pop();
+        }
+    }
+
+    // class Checkout extends Component { render() { this.props.t(...) } }
+    fn visit_mut_class_decl(&mut self, class_decl: &mut ClassDecl) {
+        self.scopes.shadow_alias(&class_decl.ident.sym);
+
+        let entered = self.scopes.enter_component(&class_decl.ident.sym);
+        class_decl.visit_mut_children_with(self);
+
+        if entered {
+            self.scopes.pop();
+        }
+    }
+
+    // const { t } = useTranslation("checkout");
+    // const t = i18n.getFixedT(null, "checkout");
+    fn visit_mut_var_declarator(&mut self, var_declarator: &mut VarDeclarator) {
+        // const Checkout = ({ t }) => {...};
+        let entered = match var_declarator.name.as_ident() {
+            Some(ident) => self.scopes.enter_component(&ident.sym),
+            None => false,
+        };
+
+        var_declarator.visit_mut_children_with(self);
+
+        if entered {
+            self.scopes.pop();
+        }
+
+        self.scopes.bind_translate_functions(var_declarator);
+    }
+
+    // t(l.common.foo...);
+    fn visit_mut_call_expr(&mut self, call_expr: &mut CallExpr) {
+        // required to ensure that other visit_mut fn are called for children
+        call_expr.callee.visit_mut_with(self);
+        call_expr.type_args.visit_mut_with(self);
+
+        // t functions bound to namespaces are given keys relative to their default namespace
+        let callee = builders::utils::callee_path(&call_expr.callee);
+
+        // In targeted mode only the arguments expecting a key are converted
+        let sink_arguments = callee
+            .as_deref()
+            .map(|callee| self.scopes.sink_arguments(&self.config.sinks, callee))
+            .unwrap_or_default();
+
+        let translate_function = callee
+            .as_deref()
+            .and_then(|callee| self.scopes.translate_function(callee))
+            .cloned();
+
+        let in_sink_call = mem::replace(&mut self.in_sink_call, !sink_arguments.is_empty());
+
+        // We must loop through all args of the call_expr for cases like so:
+        // ex: mobileHeaderContent(faChevronDown, l.common.ShowOrderSummary)
+        for (i, arg) in call_expr.args.iter_mut().enumerate() {
+            let in_sink = mem::replace(&mut self.in_sink, sink_arguments.contains(&i));
+
+            arg.visit_mut_with(self);
+
+            // t([l.errors.specific, l.errors.generic]);
+            if let (true, Expr::Array(array)) = (self.in_sink, &mut *arg.expr) {
+                let translate_function = match (i, &callee, &translate_function) {
+                    (0, Some(callee), Some(translate_function)) => {
+                        Some((callee.as_str(), translate_function))
+                    }
+                    _ => None,
+                };
+
+                self.convert_fallback_keys(array, translate_function);
+            }
+
+            // t(l.common.foobar);
+            if arg.expr.is_member() {
+                let member_expr = arg.expr.as_member().unwrap();
+
+                if let Some(mut box_expr) = self.convert(member_expr, arg.span()) {
+                    if let (0, Some(callee), Some(translate_function)) =
+                        (i, &callee, &translate_function)
+                    {
+                        self.shorten_key(&mut box_expr, member_expr, callee, translate_function);
+                    }
+
+                    *arg = ExprOrSpread {
+                        spread: None,
+                        expr: box_expr,
+                    }
+                }
+            }
+
+            self.in_sink = in_sink;
+        }
+
+        self.in_sink_call = in_sink_call;
+    }
+
+    // <Trans i18nKey={l.common.foobar} />
+    fn visit_mut_jsx_opening_element(&mut self, jsx_opening_element: &mut JSXOpeningElement) {
+        jsx_opening_element.name.visit_mut_with(self);
+        jsx_opening_element.type_args.visit_mut_with(self);
+
+        let component = builders::utils::jsx_element_name(&jsx_opening_element.name);
+
+        for attr in jsx_opening_element.attrs.iter_mut() {
+            let sink = match attr {
+                JSXAttrOrSpread::JSXAttr(JSXAttr {
+                    name: JSXAttrName::Ident(name),
+                    ..
+                }) => scope::is_jsx_sink(&self.config.sinks, &component, &name.sym),
+                _ => false,
+            };
+
+            let in_sink = mem::replace(&mut self.in_sink, sink);
+            attr.visit_mut_with(self);
+            self.in_sink = in_sink;
+        }
+    }
+
+    // for cases where the translation is nested inside a conditional statement somewhere
+    // isFoo ? l.common.foo : l.common.bar;
+    fn visit_mut_cond_expr(&mut self, cond_expr: &mut CondExpr) {
+        // required to ensure that other visit_mut fn are called for children
+        cond_expr.visit_mut_children_with(self);
+
+        // cons here being the first result in our ternary if truthy
+        // from the above example comment that would be l.common.foo
+        if cond_expr.cons.is_member() {
+            let member_expr = cond_expr.cons.as_member().unwrap();
+
+            if let Some(box_expr) =
+                self.convert_at(Position::Conditional, member_expr, cond_expr.cons.span())
+            {
+                cond_expr.cons = box_expr;
+            }
+        }
+
+        // alt here being the second result in our ternary if falsy
+        // from the above example comment that would be l.common.bar
+        if cond_expr.alt.is_member() {
+            let member_expr = cond_expr.alt.as_member().unwrap();
+
+            if let Some(box_expr) =
+                self.convert_at(Position::Conditional, member_expr, cond_expr.alt.span())
+            {
+                cond_expr.alt = box_expr;
+            }
+        }
+    }
+
+    // for cases where the translation is returned as part of a function and not nested inside of a t call
+    // const func = () => l.common.foobar;
+    fn visit_mut_return_stmt(&mut self, return_stmt: &mut ReturnStmt) {
+        // required to ensure that other visit_mut fn are called for children
+        return_stmt.visit_mut_children_with(self);
+
+        // If there's no arguments to our return statement, we don't need to do anything
+        if return_stmt.arg.is_none() {
+            return;
+        }
+
+        // we only care about the first argument in a t expression
+        // second argument could be variables: t(l.common.foobar, { count });
+        // when second argument is a translation, that is handled by visit_mut_key_value_prop
+        let arg = return_stmt.arg.clone().unwrap();
+
+        if arg.is_member() {
+            let member_expr = arg.as_member().unwrap();
+            let box_expr = self.convert_at(Position::Return, member_expr, return_stmt.arg.span());
+
+            if box_expr.is_some() {
+                return_stmt.arg = box_expr;
+            }
+        }
+    }
+
+    // for cases where translation is used as a variable
+    // t(l.common.foobar, { label: l.common.label });
+    fn visit_mut_key_value_prop(&mut self, key_value_prop: &mut KeyValueProp) {
+        // required to ensure that other visit_mut fn are called for children
+        key_value_prop.visit_mut_children_with(self);
+
+        if key_value_prop.value.is_member() {
+            let member_expr = key_value_prop.value.as_member().unwrap();
+
+            if let Some(box_expr) =
+                self.convert_at(Position::Property, member_expr, key_value_prop.value.span())
+            {
+                key_value_prop.value = box_expr;
+            }
+        }
+    }
+
+    // for cases where the translation is rendered, the key would be displayed as is
+    // <p>{l.common.foobar}</p> -> <p>{t("common:foobar")}</p>
+    fn visit_mut_jsx_element_child(&mut self, child: &mut JSXElementChild) {
+        // rendered translations are never passed to a sink
+        let in_sink = mem::replace(&mut self.in_sink, false);
+        let in_sink_call = mem::replace(&mut self.in_sink_call, false);
+
+        // other children such as elements are visited as usual
+        match child {
+            JSXElementChild::JSXExprContainer(jsx_expr_cont) => {
+                self.visit_mut_displayed_jsx_expr_container(jsx_expr_cont, true)
+            }
+            _ => child.visit_mut_children_with(self),
+        }
+
+        self.in_sink = in_sink;
+        self.in_sink_call = in_sink_call;
+    }
+
+    // for cases where the translation is displayed by the component through one of its attributes
+    // <Button label={l.common.save} /> -> <Button label={t("common:save")} />
+    fn visit_mut_jsx_attr(&mut self, jsx_attr: &mut JSXAttr) {
+        let wrapped = match &jsx_attr.name {
+            JSXAttrName::Ident(name) => self
+                .config
+                .auto_wrap
+                .attributes
+                .iter()
+                .any(|attribute| attribute == &name.sym as &str),
+            JSXAttrName::JSXNamespacedName(_) => false,
+        };
+
+        // other attributes such as i18nKey are handled by visit_mut_jsx_expr_container
+        let Some(JSXAttrValue::JSXExprContainer(jsx_expr_cont)) = &mut jsx_attr.value else {
+            return jsx_attr.visit_mut_children_with(self);
+        };
+
+        if !wrapped {
+            return jsx_expr_cont.visit_mut_with(self);
+        }
+
+        self.visit_mut_displayed_jsx_expr_container(jsx_expr_cont, false);
+    }
+
+    fn visit_mut_jsx_expr_container(&mut self, jsx_expr_cont: &mut JSXExprContainer) {
+        if self.is_ignored(jsx_expr_cont) {
+            return;
+        }
+
+        // required to ensure that other visit_mut fn are called for children
+        jsx_expr_cont.visit_mut_children_with(self);
+
+        // <Trans i18nKey={[l.errors.specific, l.errors.generic]} />
+        if let (true, JSXExpr::Expr(expr)) = (self.in_sink, &mut jsx_expr_cont.expr) {
+            if let Expr::Array(array) = &mut **expr {
+                self.convert_fallback_keys(array, None);
+            }
+        }
+
+        match jsx_expr_cont.expr.clone() {
+            JSXExpr::JSXEmptyExpr(_) => (),
+            JSXExpr::Expr(expr) => {
+                if expr.is_member() {
+                    let member_expr = expr.as_member().unwrap();
+
+                    if let Some(box_expr) = self.convert(member_expr, jsx_expr_cont.span()) {
+                        jsx_expr_cont.expr = JSXExpr::Expr(box_expr);
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,220 @@
+use swc_core::common::{comments::Comments, BytePos, Span, Spanned};
+use swc_ecma_ast::{
+    ArrowExpr, BlockStmt, CallExpr, ClassDecl, Expr, FnDecl, Function, JSXAttrName,
+    JSXAttrOrSpread, JSXAttrValue, JSXExpr, JSXOpeningElement, Lit, ModuleItem, PropOrSpread, Stmt,
+    VarDeclarator,
+};
+use swc_ecma_visit::{Visit, VisitWith};
+
+use crate::{
+    builders,
+    config::Config,
+    diagnostics::{self, Severity},
+    pragmas,
+    scope::{self, Scopes, TranslateFunction},
+};
+
+/// Reports the keys written as strings in translation sinks, the program is never modified
+/// t("common:save") -> "common:save" bypasses the type checking of l, use l.common.save instead
+pub struct Linter<'a, C: Comments> {
+    comments: &'a C,
+    config: &'a Config,
+    /// Same bindings and sinks as the converter, see scope::Scopes
+    scopes: Scopes,
+}
+
+impl<'a, C: Comments> Linter<'a, C> {
+    pub fn new(comments: &'a C, config: &'a Config, scopes: Scopes) -> Self {
+        Linter {
+            comments,
+            config,
+            scopes,
+        }
+    }
+
+    // t(/* translation-converter-ignore */ "common:save")
+    fn check_key(&self, key: &Expr, translate_function: Option<&TranslateFunction>) {
+        if pragmas::has_leading_pragma(self.comments, key.span().lo, pragmas::IGNORE) {
+            return;
+        }
+
+        match key {
+            // t(["errors:specific", "errors:generic"])
+            Expr::Array(array) => {
+                for elem in array.elems.iter().flatten() {
+                    self.check_key(&elem.expr, translate_function);
+                }
+            }
+            Expr::Lit(Lit::Str(_)) | Expr::Tpl(_) => self.report(key, translate_function),
+            _ => (),
+        }
+    }
+
+    fn report(&self, key: &Expr, translate_function: Option<&TranslateFunction>) {
+        let suggestion = scope::key_translation(key, translate_function)
+            .as_deref()
+            .and_then(Expr::as_member)
+            .map(builders::serializers::member_path)
+            .unwrap_or_else(|| "an l translation".to_string());
+
+        let severity = if self.config.strict {
+            Severity::Error
+        } else {
+            Severity::Warning
+        };
+
+        diagnostics::emit(
+            severity,
+            key.span(),
+            &format!("string keys bypass the type checking of l, use {suggestion} instead"),
+        );
+    }
+
+    fn is_disabled(&self, span: Span) -> bool {
+        pragmas::has_leading_pragma(self.comments, span.lo, pragmas::DISABLE_NEXT_LINE)
+    }
+}
+
+impl<C: Comments> Visit for Linter<'_, C> {
+    fn visit_module_item(&mut self, module_item: &ModuleItem) {
+        if !self.is_disabled(module_item.span()) {
+            module_item.visit_children_with(self);
+        }
+    }
+
+    fn visit_stmt(&mut self, stmt: &Stmt) {
+        if !self.is_disabled(stmt.span()) {
+            stmt.visit_children_with(self);
+        }
+    }
+
+    fn visit_prop_or_spread(&mut self, prop: &PropOrSpread) {
+        if !self.is_disabled(prop.span()) {
+            prop.visit_children_with(self);
+        }
+    }
+
+    fn visit_expr(&mut self, expr: &Expr) {
+        if !pragmas::has_leading_pragma(self.comments, expr.span().lo, pragmas::IGNORE) {
+            expr.visit_children_with(self);
+        }
+    }
+
+    fn visit_function(&mut self, function: &Function) {
+        self.scopes.push();
+        function.visit_children_with(self);
+        self.scopes.pop();
+    }
+
+    fn visit_arrow_expr(&mut self, arrow_expr: &ArrowExpr) {
+        self.scopes.push();
+        arrow_expr.visit_children_with(self);
+        self.scopes.pop();
+    }
+
+    fn visit_block_stmt(&mut self, block: &BlockStmt) {
+        self.scopes.push();
+        block.visit_children_with(self);
+        self.scopes.pop();
+    }
+
+    fn visit_fn_decl(&mut self, fn_decl: &FnDecl) {
+        let entered = self.scopes.enter_component(&fn_decl.ident.sym);
+        fn_decl.visit_children_with(self);
+
+        if entered {
+            self.scopes.pop();
+        }
+    }
+
+    fn visit_class_decl(&mut self, class_decl: &ClassDecl) {
+        let entered = self.scopes.enter_component(&class_decl.ident.sym);
+        class_decl.visit_children_with(self);
+
+        if entered {
+            self.scopes.pop();
+        }
+    }
+
+    // const { t } = useTranslation("checkout");
+    // const t = i18n.getFixedT(null, "checkout");
+    fn visit_var_declarator(&mut self, var_declarator: &VarDeclarator) {
+        let entered = match var_declarator.name.as_ident() {
+            Some(ident) => self.scopes.enter_component(&ident.sym),
+            None => false,
+        };
+
+        var_declarator.visit_children_with(self);
+
+        if entered {
+            self.scopes.pop();
+        }
+
+        self.scopes.bind_translate_functions(var_declarator);
+    }
+
+    // t("common:save");
+    fn visit_call_expr(&mut self, call_expr: &CallExpr) {
+        call_expr.visit_children_with(self);
+
+        let Some(callee) = builders::utils::callee_path(&call_expr.callee) else {
+            return;
+        };
+
+        let sink_arguments = self.scopes.sink_arguments(&self.config.sinks, &callee);
+
+        for (i, arg) in call_expr.args.iter().enumerate() {
+            if !sink_arguments.contains(&i) {
+                continue;
+            }
+
+            // only the key of a t function is relative to its namespace and key prefix
+            let translate_function = match i {
+                0 => self.scopes.translate_function(&callee),
+                _ => None,
+            };
+
+            self.check_key(&arg.expr, translate_function);
+        }
+    }
+
+    // <Trans i18nKey="common:welcome" />
+    fn visit_jsx_opening_element(&mut self, jsx_opening_element: &JSXOpeningElement) {
+        jsx_opening_element.visit_children_with(self);
+
+        let component = builders::utils::jsx_element_name(&jsx_opening_element.name);
+
+        for attr in &jsx_opening_element.attrs {
+            let JSXAttrOrSpread::JSXAttr(attr) = attr else {
+                continue;
+            };
+
+            let JSXAttrName::Ident(name) = &attr.name else {
+                continue;
+            };
+
+            if !scope::is_jsx_sink(&self.config.sinks, &component, &name.sym) {
+                continue;
+            }
+
+            match &attr.value {
+                Some(JSXAttrValue::Str(str)) => {
+                    self.check_key(&Expr::Lit(Lit::Str(str.clone())), None)
+                }
+                // <Trans i18nKey={/* translation-converter-ignore */ "common:welcome"} />
+                Some(JSXAttrValue::JSXExprContainer(jsx_expr_cont)) => {
+                    let after_brace = jsx_expr_cont.span.lo + BytePos(1);
+
+                    if pragmas::has_trailing_pragma(self.comments, after_brace, pragmas::IGNORE) {
+                        continue;
+                    }
+
+                    if let JSXExpr::Expr(expr) = &jsx_expr_cont.expr {
+                        self.check_key(expr, None);
+                    }
+                }
+                _ => (),
+            }
+        }
+    }
+}
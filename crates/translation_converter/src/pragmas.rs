@@ -0,0 +1,36 @@
+use swc_core::common::{comments::Comments, BytePos};
+
+/// Disables the converter for the whole file when found at the top of it
+/// ex: // translation-converter-disable
+pub const DISABLE: &str = "translation-converter-disable";
+
+/// Disables the converter for the statement following the comment
+/// ex: // translation-converter-disable-next-line
+pub const DISABLE_NEXT_LINE: &str = "translation-converter-disable-next-line";
+
+/// Disables the converter for the expression following the comment
+/// ex: t(/* translation-converter-ignore */ l.common.foobar)
+pub const IGNORE: &str = "translation-converter-ignore";
+
+/// Checks whether any of the comments leading the given position is the specified pragma
+///
+/// # Examples
+/// ```text
+/// // translation-converter-disable-next-line
+/// const label = l.common.foobar;
+///
+/// has_leading_pragma(&comments, stmt.span.lo, DISABLE_NEXT_LINE) -> true
+/// ```
+pub fn has_leading_pragma<C: Comments>(comments: &C, pos: BytePos, pragma: &str) -> bool {
+    // Comments are stored without their delimiters, "// foo" and "/* foo */" both have " foo " as text
+    comments
+        .get_leading(pos)
+        .is_some_and(|comments| comments.iter().any(|comment| comment.text.trim() == pragma))
+}
+
+/// Checks whether any of the comments trailing the given position is the specified pragma
+pub fn has_trailing_pragma<C: Comments>(comments: &C, pos: BytePos, pragma: &str) -> bool {
+    comments
+        .get_trailing(pos)
+        .is_some_and(|comments| comments.iter().any(|comment| comment.text.trim() == pragma))
+}
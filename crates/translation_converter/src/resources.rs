@@ -0,0 +1,207 @@
+use serde_json::Value;
+use std::{
+    collections::{BTreeMap, HashMap},
+    fs,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex, OnceLock, PoisonError},
+};
+
+/// Suffixes i18next appends to the keys of plural forms, ordinal forms first as they end with a cardinal suffix
+/// ex: items_one, items_other, place_ordinal_one
+const PLURAL_SUFFIXES: [&str; 12] = [
+    "_ordinal_zero",
+    "_ordinal_one",
+    "_ordinal_two",
+    "_ordinal_few",
+    "_ordinal_many",
+    "_ordinal_other",
+    "_zero",
+    "_one",
+    "_two",
+    "_few",
+    "_many",
+    "_other",
+];
+
+/// Translations of a language, loaded from a directory containing a JSON file per namespace
+/// ex: public/locales/en/common.json, public/locales/en/checkout.json
+pub struct Resources {
+    namespaces: BTreeMap<String, Value>,
+}
+
+impl Resources {
+    /// Reads every JSON file of dir, returns an error when one of them can't be read or parsed
+    pub fn load(dir: &Path) -> Result<Resources, String> {
+        let entries = fs::read_dir(dir)
+            .map_err(|error| format!("unable to read resources {}: {error}", dir.display()))?;
+
+        let mut namespaces = BTreeMap::new();
+
+        for entry in entries {
+            let path = entry
+                .map_err(|error| format!("unable to read resources {}: {error}", dir.display()))?
+                .path();
+
+            if path.extension().is_none_or(|extension| extension != "json") {
+                continue;
+            }
+
+            let Some(namespace) = path.file_stem().and_then(|stem| stem.to_str()) else {
+                continue;
+            };
+
+            let resource = fs::read_to_string(&path)
+                .map_err(|error| format!("unable to read resource {}: {error}", path.display()))?;
+
+            let value = serde_json::from_str(&resource)
+                .map_err(|error| format!("invalid resource {}: {error}", path.display()))?;
+
+            namespaces.insert(namespace.to_string(), value);
+        }
+
+        Ok(Resources { namespaces })
+    }
+
+    /// Loads the resources of dir once per process, they are shared by every file compiled afterwards
+    /// failures are not cached so fixing the resources doesn't require a restart
+    pub fn cached(dir: &Path) -> Result<Arc<Resources>, String> {
+        static CACHE: OnceLock<Mutex<HashMap<PathBuf, Arc<Resources>>>> = OnceLock::new();
+
+        let mut cache = CACHE
+            .get_or_init(Default::default)
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+
+        if let Some(resources) = cache.get(dir) {
+            return Ok(resources.clone());
+        }
+
+        let resources = Arc::new(Resources::load(dir)?);
+        cache.insert(dir.to_path_buf(), resources.clone());
+
+        Ok(resources)
+    }
+
+    /// Resources keyed by namespace, sorted so outputs generated from them are stable
+    /// ex: public/locales/en/common.json -> { "common": {...} }
+    pub fn namespaces(&self) -> &BTreeMap<String, Value> {
+        &self.namespaces
+    }
+
+    pub fn into_namespaces(self) -> BTreeMap<String, Value> {
+        self.namespaces
+    }
+
+    /// Whether a converted key exists, plural forms count as the key they are a form of
+    /// ex: "errors:generic", "cart:items" with { "items_one": "...", "items_other": "..." }
+    pub fn has_key(&self, key: &str) -> bool {
+        let Some((namespace, path)) = key.split_once(':') else {
+            return false;
+        };
+
+        let Some(mut value) = self.namespaces.get(namespace) else {
+            return false;
+        };
+
+        let mut segments: Vec<&str> = path.split('.').collect();
+        let last = segments.pop().unwrap_or_default();
+
+        for segment in segments {
+            match value.get(segment) {
+                Some(child) => value = child,
+                None => return false,
+            }
+        }
+
+        let Some(object) = value.as_object() else {
+            return false;
+        };
+
+        // objects of translations aren't keys, ex: errors:payment
+        object
+            .iter()
+            .any(|(key, value)| value.is_string() && plural_key(key).unwrap_or(key) == last)
+    }
+}
+
+/// Key a plural form belongs to, ex: "common:items_one" -> Some("common:items")
+pub fn plural_key(key: &str) -> Option<&str> {
+    PLURAL_SUFFIXES
+        .iter()
+        .find_map(|suffix| key.strip_suffix(suffix))
+}
+
+#[cfg(test)]
+mod tests {
+    mod has_key {
+        use std::path::Path;
+
+        use crate::resources::Resources;
+
+        fn resources() -> Resources {
+            Resources::load(Path::new("tests/resources/en")).unwrap()
+        }
+
+        #[test]
+        fn returns_true_when_key_exists() {
+            assert!(resources().has_key("errors:generic"));
+            assert!(resources().has_key("errors:payment.declined"));
+        }
+
+        #[test]
+        fn returns_true_for_plural_forms() {
+            assert!(resources().has_key("common:items"));
+            assert!(resources().has_key("common:place"));
+        }
+
+        #[test]
+        fn returns_false_when_key_is_missing() {
+            assert!(!resources().has_key("errors:missing"));
+            assert!(!resources().has_key("errors:generic.nested"));
+            assert!(!resources().has_key("orders:status"));
+            assert!(!resources().has_key("generic"));
+        }
+
+        #[test]
+        fn returns_false_for_objects_and_keys_sharing_a_prefix() {
+            assert!(!resources().has_key("errors:payment"));
+            assert!(!resources().has_key("errors:unknown"));
+        }
+    }
+
+    mod plural_key {
+        use crate::resources::plural_key;
+
+        #[test]
+        fn strips_plural_suffixes() {
+            assert_eq!(plural_key("common:items_one"), Some("common:items"));
+            assert_eq!(plural_key("common:items_other"), Some("common:items"));
+            assert_eq!(plural_key("common:place_ordinal_two"), Some("common:place"));
+            assert_eq!(plural_key("common:items"), None);
+        }
+    }
+
+    mod load {
+        use std::path::Path;
+
+        use crate::resources::Resources;
+
+        #[test]
+        fn returns_an_error_when_the_directory_is_missing() {
+            let error = Resources::load(Path::new("tests/resources/missing"))
+                .err()
+                .unwrap();
+
+            assert!(error.starts_with("unable to read resources tests/resources/missing"));
+        }
+
+        #[test]
+        fn returns_an_error_when_a_resource_is_invalid() {
+            let error = Resources::load(Path::new("tests/resources/invalid"))
+                .err()
+                .unwrap();
+
+            assert!(error.starts_with("invalid resource tests/resources/invalid/common.json"));
+        }
+    }
+}
@@ -0,0 +1,280 @@
+use std::collections::{HashMap, HashSet};
+use swc_core::common::Spanned;
+use swc_ecma_ast::{Expr, VarDeclarator};
+
+use crate::{
+    builders,
+    config::{Sinks, USE_TRANSLATION},
+};
+
+// Binding and sink resolution shared by the converter, the linter and the CLI
+// so they always agree on which calls are translation sinks and what their keys refer to
+
+/// A t function bound in scope along with what it was created with
+/// ex: const { t } = useTranslation(["checkout", "common"]);
+#[derive(Clone, Debug, Default)]
+pub struct TranslateFunction {
+    // Namespaces loaded by the t function, the first one being its default namespace
+    // empty when they are not known, ex: useTranslation(), useTranslation(namespace)
+    pub namespaces: Vec<String>,
+    // Prepended to every key given to the t function
+    // ex: useTranslation("checkout", { keyPrefix: "summary" })
+    pub key_prefix: Option<String>,
+}
+
+/// Bindings of a function or a block, or of the module for the outermost scope
+#[derive(Default)]
+struct Scope {
+    // Identifiers bound to a translate function, ex: const { t } = useTranslation();
+    translate_functions: HashMap<String, TranslateFunction>,
+    // Identifiers bound to a part of the translations, keyed by name with the path they refer to
+    // ex: const { common } = l; -> { "common": ["common"] }
+    aliases: HashMap<String, Vec<String>>,
+    // Identifiers bound to anything else, they hide the aliases of the same name declared by parent scopes
+    // ex: function Foo(common) {...}
+    shadowed: HashSet<String>,
+}
+
+/// Stack of the scopes enclosing the node being visited, innermost scope last
+pub struct Scopes {
+    scopes: Vec<Scope>,
+    // components wrapped in withTranslation and the namespaces they are given
+    // export default withTranslation("checkout")(Checkout);
+    components: HashMap<String, Vec<String>>,
+}
+
+impl Default for Scopes {
+    fn default() -> Self {
+        Scopes::with_components(HashMap::new())
+    }
+}
+
+impl Scopes {
+    /// components are usually collected with builders::utils::with_translation_components
+    pub fn with_components(components: HashMap<String, Vec<String>>) -> Self {
+        Scopes {
+            scopes: vec![Scope::default()],
+            components,
+        }
+    }
+
+    /// Called when entering a function or a block
+    pub fn push(&mut self) {
+        self.scopes.push(Scope::default());
+    }
+
+    /// Called when leaving a function or a block, the bindings it declared are dropped
+    pub fn pop(&mut self) {
+        self.scopes.pop();
+    }
+
+    pub fn bind_translate_function(&mut self, name: &str, translate_function: TranslateFunction) {
+        if let Some(scope) = self.scopes.last_mut() {
+            scope
+                .translate_functions
+                .insert(name.to_string(), translate_function);
+        }
+    }
+
+    /// The translate function name refers to, bindings of inner scopes shadow the ones of their parents
+    pub fn translate_function(&self, name: &str) -> Option<&TranslateFunction> {
+        self.scopes
+            .iter()
+            .rev()
+            .find_map(|scope| scope.translate_functions.get(name))
+    }
+
+    pub fn bind_alias(&mut self, name: &str, path: Vec<String>) {
+        if let Some(scope) = self.scopes.last_mut() {
+            scope.shadowed.remove(name);
+            scope.aliases.insert(name.to_string(), path);
+        }
+    }
+
+    /// Binds name to something other than translations in the current scope
+    pub fn shadow_alias(&mut self, name: &str) {
+        if let Some(scope) = self.scopes.last_mut() {
+            scope.aliases.remove(name);
+            scope.shadowed.insert(name.to_string());
+        }
+    }
+
+    /// Path of the translations name refers to, ex: const ns = l.checkout; -> ns -> ["checkout"]
+    /// None when the innermost binding of name is not an alias
+    pub fn alias(&self, name: &str) -> Option<&[String]> {
+        for scope in self.scopes.iter().rev() {
+            if let Some(path) = scope.aliases.get(name) {
+                return Some(path);
+            }
+
+            if scope.shadowed.contains(name) {
+                return None;
+            }
+        }
+
+        None
+    }
+
+    /// Names of the aliases declared in the current scope
+    pub fn declared_aliases(&self) -> Vec<String> {
+        self.scopes
+            .last()
+            .map(|scope| scope.aliases.keys().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    /// Whether name is bound in the current scope or any of its parents, whatever it is bound to
+    /// ex: function Foo({ t }) {...}, const t = props.t;
+    pub fn is_bound(&self, name: &str) -> bool {
+        self.scopes.iter().any(|scope| {
+            scope.translate_functions.contains_key(name)
+                || scope.aliases.contains_key(name)
+                || scope.shadowed.contains(name)
+        })
+    }
+
+    /// Whether name refers to a translate function in the current scope or any of its parents
+    pub fn is_translate_function(&self, name: &str) -> bool {
+        self.translate_function(name).is_some()
+    }
+
+    /// Components wrapped in withTranslation receive a t function bound to the namespaces it was given
+    /// returns whether a scope was pushed for the component, it needs to be popped once the component is visited
+    pub fn enter_component(&mut self, name: &str) -> bool {
+        let Some(namespaces) = self.components.get(name).cloned() else {
+            return false;
+        };
+
+        self.push();
+
+        // function Checkout({ t }), function Checkout(props) { props.t(...) }, this.props.t(...)
+        for path in ["t", "props.t", "this.props.t"] {
+            self.bind_translate_function(
+                path,
+                TranslateFunction {
+                    namespaces: namespaces.clone(),
+                    key_prefix: None,
+                },
+            );
+        }
+
+        true
+    }
+
+    /// Binds the translate functions a declarator creates
+    /// const { t } = useTranslation("checkout");
+    /// const t = i18n.getFixedT(null, "checkout");
+    pub fn bind_translate_functions(&mut self, declarator: &VarDeclarator) {
+        let Some(Expr::Call(call_expr)) = declarator.init.as_deref() else {
+            return;
+        };
+
+        let Some(path) = builders::utils::callee_path(&call_expr.callee) else {
+            return;
+        };
+
+        if path == USE_TRANSLATION {
+            let translate_function = TranslateFunction {
+                namespaces: builders::utils::namespaces_argument(call_expr.args.first()),
+                key_prefix: builders::utils::key_prefix_option(call_expr.args.get(1)),
+            };
+
+            for name in builders::utils::translate_function_bindings(&declarator.name) {
+                self.bind_translate_function(&name, translate_function.clone());
+            }
+        } else if path == "getFixedT" || path.ends_with(".getFixedT") {
+            let Some(ident) = declarator.name.as_ident() else {
+                return;
+            };
+
+            // getFixedT(lng, ns, keyPrefix)
+            let translate_function = TranslateFunction {
+                namespaces: builders::utils::namespaces_argument(call_expr.args.get(1)),
+                key_prefix: builders::utils::key_prefix_argument(call_expr.args.get(2)),
+            };
+
+            self.bind_translate_function(&ident.sym, translate_function);
+        }
+    }
+
+    /// Indices of the arguments of a call to callee expecting a key
+    /// ex: t(l.common.foobar, { count }) -> [0]
+    /// useTranslation("checkout") itself is given namespaces, only the t functions it returns are sinks
+    pub fn sink_arguments(&self, sinks: &Sinks, callee: &str) -> Vec<usize> {
+        sinks
+            .callees
+            .iter()
+            .filter(|sink| {
+                if sink.callee == USE_TRANSLATION {
+                    self.is_translate_function(callee)
+                } else {
+                    sink.callee == callee
+                }
+            })
+            .flat_map(|sink| sink.arguments.iter().copied())
+            .collect()
+    }
+}
+
+/// Whether the attribute of the component expects a key, ex: <Trans i18nKey={l.common.foobar} />
+pub fn is_jsx_sink(sinks: &Sinks, component: &str, attribute: &str) -> bool {
+    sinks
+        .jsx
+        .iter()
+        .any(|sink| sink.component == component && sink.attribute == attribute)
+}
+
+/// The translation a key refers to, the namespace and key prefix of the t function it is given to are added back
+/// const { t } = useTranslation("checkout", { keyPrefix: "summary" }); t("total") -> l.checkout.summary.total
+/// None when the key can't be written as a translation, ex: t("foo-bar"), t(`common:foo${bar}`)
+pub fn key_translation(
+    key: &Expr,
+    translate_function: Option<&TranslateFunction>,
+) -> Option<Box<Expr>> {
+    let (namespace, identifiers) = builders::serializers::key_identifiers(key)?;
+
+    let namespace = match (namespace, translate_function) {
+        (Some(namespace), _) => namespace,
+        (None, Some(translate_function)) => {
+            builders::serializers::ident_expr(translate_function.namespaces.first()?)
+        }
+        (None, None) => return None,
+    };
+
+    let key_prefix = translate_function
+        .and_then(|translate_function| translate_function.key_prefix.as_deref())
+        .map(|key_prefix| {
+            key_prefix
+                .split('.')
+                .map(builders::serializers::ident_expr)
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let exprs = [vec![namespace], key_prefix, identifiers].concat();
+
+    Some(builders::lib::translation_member(exprs, key.span()))
+}
+
+/// The key a string key refers to, the namespace and key prefix of the t function it is given to are added back
+/// const { t } = useTranslation("checkout", { keyPrefix: "summary" }); t("total") -> "checkout:summary.total"
+/// interpolated values are kept, ex: t(`errors:${code}`) -> "errors:${code}"
+pub fn full_key(key: &Expr, translate_function: Option<&TranslateFunction>) -> Option<String> {
+    let key = crate::key_string(key)?;
+
+    let (namespace, path) = match key.split_once(':') {
+        Some((namespace, path)) => (namespace, path),
+        None => (
+            translate_function?.namespaces.first()?.as_str(),
+            key.as_str(),
+        ),
+    };
+
+    let key_prefix =
+        translate_function.and_then(|translate_function| translate_function.key_prefix.as_deref());
+
+    Some(match key_prefix {
+        Some(key_prefix) => format!("{namespace}:{key_prefix}.{path}"),
+        None => format!("{namespace}:{path}"),
+    })
+}
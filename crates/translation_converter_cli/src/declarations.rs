@@ -0,0 +1,160 @@
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+use crate::resources::{translations, Translation};
+
+/// TypeScript declarations of the l tree, generated from the resources of the source locale
+/// every leaf is typed as the key it is converted into, plural forms are collapsed into the key they belong to
+///
+/// # Examples
+/// ```text
+/// // common.json: { "save": "Save", "items_one": "{{count}} item", "items_other": "{{count}} items" }
+/// let declarations = declarations(&resources);
+///
+/// // export declare const l: {
+/// //   readonly common: {
+/// //     readonly save: "common:save";
+/// //     readonly items: "common:items";
+/// //   };
+/// // };
+/// //
+/// // export type TranslationKey =
+/// //   | "common:save"
+/// //   | "common:items";
+/// ```
+pub fn declarations(resources: &BTreeMap<String, Value>) -> String {
+    let mut declarations =
+        String::from("// Generated by translation-converter from the resources, do not edit\n\n");
+
+    let l = Translation::Object(
+        resources
+            .iter()
+            .map(|(namespace, resource)| (namespace.clone(), translations(namespace, resource)))
+            .collect(),
+    );
+    let mut keys = vec![];
+
+    declarations += "export declare const l: ";
+    declaration(&l, 0, &mut declarations, &mut keys);
+    declarations += ";\n\n";
+
+    // a union of every key, for the functions taking a key rather than a translation
+    declarations += "export type TranslationKey =";
+
+    if keys.is_empty() {
+        declarations += " never";
+    }
+
+    for key in &keys {
+        declarations += &format!("\n  | {}", string(key));
+    }
+
+    declarations += ";\n";
+
+    declarations
+}
+
+fn declaration<'a>(
+    translation: &'a Translation,
+    depth: usize,
+    declarations: &mut String,
+    keys: &mut Vec<&'a str>,
+) {
+    let translations = match translation {
+        Translation::Key(key) => {
+            *declarations += &string(key);
+            keys.push(key);
+            return;
+        }
+        Translation::Object(translations) if translations.is_empty() => {
+            *declarations += "{}";
+            return;
+        }
+        Translation::Object(translations) => translations,
+    };
+
+    let indent = "  ".repeat(depth);
+
+    *declarations += "{\n";
+
+    for (name, translation) in translations {
+        *declarations += &format!("{indent}  readonly {}: ", property(name));
+        declaration(translation, depth + 1, declarations, keys);
+        *declarations += ";\n";
+    }
+
+    *declarations += &format!("{indent}}}");
+}
+
+/// Properties that aren't identifiers are quoted, ex: foo-bar -> "foo-bar"
+pub fn property(name: &str) -> String {
+    let mut chars = name.chars();
+
+    let is_identifier = chars
+        .next()
+        .is_some_and(|char| char.is_ascii_alphabetic() || char == '_' || char == '$')
+        && chars.all(|char| char.is_ascii_alphanumeric() || char == '_' || char == '$');
+
+    match is_identifier {
+        true => name.to_string(),
+        false => string(name),
+    }
+}
+
+pub fn string(value: &str) -> String {
+    Value::String(value.to_string()).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use std::collections::BTreeMap;
+
+    use crate::declarations::declarations;
+
+    #[test]
+    fn declares_the_translations_and_their_keys() {
+        let resources = BTreeMap::from([
+            (
+                "common".to_string(),
+                json!({
+                    "save": "Save",
+                    "items_one": "{{count}} item",
+                    "items_other": "{{count}} items",
+                    "form": { "submit": "Submit", "foo-bar": "Foo" }
+                }),
+            ),
+            ("checkout-v2".to_string(), json!({})),
+        ]);
+
+        assert_eq!(
+            declarations(&resources),
+            r#"// Generated by translation-converter from the resources, do not edit
+
+export declare const l: {
+  readonly "checkout-v2": {};
+  readonly common: {
+    readonly save: "common:save";
+    readonly items: "common:items";
+    readonly form: {
+      readonly submit: "common:form.submit";
+      readonly "foo-bar": "common:form.foo-bar";
+    };
+  };
+};
+
+export type TranslationKey =
+  | "common:save"
+  | "common:items"
+  | "common:form.submit"
+  | "common:form.foo-bar";
+"#
+        );
+    }
+
+    #[test]
+    fn declares_no_key_without_resources() {
+        assert!(declarations(&BTreeMap::new())
+            .ends_with("export declare const l: {};\n\nexport type TranslationKey = never;\n"));
+    }
+}
@@ -0,0 +1,83 @@
+use swc_core::common::BytePos;
+
+/// Replaces the source between lo and hi with text, inserts text when lo and hi are equal
+pub struct Edit {
+    pub lo: BytePos,
+    pub hi: BytePos,
+    pub text: String,
+}
+
+/// Applies the edits to the source of a file, the ones with no span are prepended
+/// edits replacing overlapping parts of the source can't all be applied, they are returned as an error
+pub fn apply(src: &str, start_pos: BytePos, mut edits: Vec<Edit>) -> Result<String, String> {
+    edits.sort_by_key(|edit| (edit.lo, edit.hi));
+
+    let mut edited = String::new();
+    let mut offset = 0;
+
+    for edit in edits {
+        let (lo, hi) = if edit.lo.is_dummy() {
+            (0, 0)
+        } else {
+            (
+                edit.lo.0.wrapping_sub(start_pos.0) as usize,
+                edit.hi.0.wrapping_sub(start_pos.0) as usize,
+            )
+        };
+
+        if lo < offset {
+            return Err(format!(
+                "overlapping edits at {lo}..{hi}, the previous edit ends at {offset}"
+            ));
+        }
+
+        let unchanged = src
+            .get(offset..lo)
+            .filter(|_| lo <= hi && src.is_char_boundary(hi))
+            .ok_or_else(|| format!("edit at {lo}..{hi} is outside of the source"))?;
+
+        edited.push_str(unchanged);
+        edited.push_str(&edit.text);
+        offset = hi;
+    }
+
+    edited.push_str(&src[offset..]);
+    Ok(edited)
+}
+
+#[cfg(test)]
+mod tests {
+    use swc_core::common::BytePos;
+
+    use crate::edit::{apply, Edit};
+
+    fn edit(lo: u32, hi: u32, text: &str) -> Edit {
+        Edit {
+            lo: BytePos(lo),
+            hi: BytePos(hi),
+            text: text.to_string(),
+        }
+    }
+
+    #[test]
+    fn applies_edits_in_source_order() {
+        let edits = vec![
+            edit(11, 14, "\"common:save\""),
+            edit(1, 1, "// saved\n"),
+            edit(BytePos::DUMMY.0, BytePos::DUMMY.0, "\"use client\";\n"),
+        ];
+
+        assert_eq!(
+            apply("const a = l.b;", BytePos(1), edits).unwrap(),
+            "\"use client\";\n// saved\nconst a = \"common:save\";"
+        );
+    }
+
+    #[test]
+    fn returns_overlapping_edits_as_an_error() {
+        let edits = vec![edit(1, 6, "let"), edit(3, 8, "x")];
+
+        assert!(apply("const a = b;", BytePos(1), edits).is_err());
+        assert!(apply("const a = b;", BytePos(1), vec![edit(8, 20, "c")]).is_err());
+    }
+}
@@ -0,0 +1,413 @@
+use serde::Serialize;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    io,
+};
+use swc_core::common::{
+    errors::{Handler, HANDLER},
+    sync::Lrc,
+    BytePos, SourceMap, Spanned,
+};
+use swc_ecma_ast::Program;
+use swc_ecma_visit::VisitMutWith;
+use translation_converter::{
+    config::{ComputedNamespaces, Config, Mode},
+    scope, TranslationConverter,
+};
+
+use crate::{sinks::sink_keys, source::Source};
+
+/// A translation referenced in the source
+pub struct Reference {
+    /// Key the translation is converted into, ex: "common:foobar", "common:foo.${bar}"
+    pub key: String,
+    /// Namespace listed by the manifest, "*" for computed namespaces when namespaceManifest lists them as wildcards
+    pub namespace: Option<String>,
+    pub file: String,
+    pub line: usize,
+}
+
+/// Collects the translations referenced in a source file, keys are resolved by the converter
+/// so aliases, roots, remapped paths and deprecated keys are taken into account
+/// keys passed to sinks as strings get the namespace and key prefix of their t function
+///
+/// # Examples
+/// ```text
+/// // const { common } = l; t(common.foobar); t(l.common.foo[bar]);
+/// let references = references(&cm, &source, &config);
+///
+/// assert_eq!(references[0].key, "common:foobar");
+/// assert_eq!(references[1].key, "common:foo.${bar}");
+/// ```
+pub fn references(cm: &Lrc<SourceMap>, source: &Source, config: &Config) -> Vec<Reference> {
+    let computed = config
+        .namespace_manifest
+        .as_ref()
+        .map(|manifest| &manifest.computed);
+
+    // every translation is resolved, not only the ones passed to sinks
+    let mut converter_config = config.clone();
+    converter_config.mode = Mode::All;
+
+    let mut program = Program::Module(source.module.clone());
+    let mut converter = TranslationConverter::new(source.comments.clone(), converter_config);
+
+    // the diagnostics of the conversion are left to the plugin
+    let handler = Handler::with_emitter_writer(Box::new(io::sink()), Some(cm.clone()));
+    HANDLER.set(&handler, || program.visit_mut_with(&mut converter));
+
+    let mut keys: Vec<(String, BytePos)> = converter
+        .translations()
+        .iter()
+        .map(|translation| (translation.key.clone(), translation.span.lo))
+        .collect();
+
+    // keys still passed as strings are referenced as well, ex: t("common:save")
+    keys.extend(
+        sink_keys(source, config)
+            .into_iter()
+            .filter_map(|sink_key| {
+                let key = scope::full_key(&sink_key.key, sink_key.translate_function.as_ref())?;
+
+                Some((key, sink_key.key.span_lo()))
+            }),
+    );
+
+    // translations are converted once their computed properties are, they are listed in source order
+    keys.sort_by_key(|(_, pos)| *pos);
+
+    keys.into_iter()
+        .map(|(key, pos)| Reference {
+            namespace: match (namespace(&key), computed) {
+                (Some(namespace), _) => Some(namespace.to_string()),
+                (None, Some(ComputedNamespaces::Wildcard)) => Some("*".to_string()),
+                (None, _) => None,
+            },
+            key,
+            file: source.file.name.to_string(),
+            line: cm.lookup_char_pos(pos).line,
+        })
+        .collect()
+}
+
+/// Whether the key has interpolated values, ex: "common:foo.${bar}"
+pub fn is_template(key: &str) -> bool {
+    key.contains("${")
+}
+
+/// Static namespace of a key or pattern, ex: "common:foobar" -> Some("common"), "${ns}:foobar" -> None, "*:foobar" -> None
+pub fn namespace(key: &str) -> Option<&str> {
+    key.split_once(':')
+        .map(|(namespace, _)| namespace)
+        .filter(|namespace| !is_template(namespace) && *namespace != "*")
+}
+
+/// Replaces the interpolated values of a key with wildcards
+/// ex: "common:foo.${bar}" -> "common:foo.*", "common:foo.${bar}.baz" -> "common:foo.*.baz"
+pub fn pattern(key: &str) -> String {
+    let mut pattern = String::new();
+    let mut rest = key;
+
+    while let Some(start) = rest.find("${") {
+        pattern.push_str(&rest[..start]);
+        pattern.push('*');
+
+        rest = match rest[start..].find('}') {
+            Some(end) => &rest[start + end + 1..],
+            None => "",
+        };
+    }
+
+    pattern.push_str(rest);
+    pattern
+}
+
+#[derive(Serialize)]
+pub struct Manifest {
+    /// Static namespaces of the keys and patterns
+    pub namespaces: BTreeSet<String>,
+    pub keys: Vec<Key>,
+    /// Keys with interpolated values, ex: common:foo.*
+    pub patterns: Vec<Pattern>,
+}
+
+#[derive(Serialize)]
+pub struct Key {
+    pub key: String,
+    pub namespace: Option<String>,
+    pub locations: Vec<Location>,
+}
+
+#[derive(Serialize)]
+pub struct Pattern {
+    pub pattern: String,
+    pub namespace: Option<String>,
+    pub locations: Vec<Location>,
+}
+
+#[derive(Serialize)]
+pub struct Location {
+    pub file: String,
+    pub line: usize,
+}
+
+/// Groups the references by key, keys are sorted so the manifest is stable
+pub fn manifest(references: &[Reference]) -> Manifest {
+    let mut keys: BTreeMap<String, Vec<Location>> = BTreeMap::new();
+    let mut patterns: BTreeMap<String, Vec<Location>> = BTreeMap::new();
+
+    for reference in references {
+        let location = Location {
+            file: reference.file.clone(),
+            line: reference.line,
+        };
+
+        if is_template(&reference.key) {
+            patterns
+                .entry(pattern(&reference.key))
+                .or_default()
+                .push(location);
+        } else {
+            keys.entry(reference.key.clone())
+                .or_default()
+                .push(location);
+        }
+    }
+
+    Manifest {
+        namespaces: references
+            .iter()
+            .filter_map(|reference| reference.namespace.clone())
+            .collect(),
+        keys: keys
+            .into_iter()
+            .map(|(key, locations)| Key {
+                namespace: namespace(&key).map(str::to_string),
+                key,
+                locations,
+            })
+            .collect(),
+        patterns: patterns
+            .into_iter()
+            .map(|(pattern, locations)| Pattern {
+                namespace: namespace(&pattern).map(str::to_string),
+                pattern,
+                locations,
+            })
+            .collect(),
+    }
+}
+
+/// One row per reference, ex: static,common:foobar,common,src/Foo.tsx,12
+pub fn csv(manifest: &Manifest) -> String {
+    let mut csv = String::from("type,key,namespace,file,line\n");
+
+    let keys = manifest
+        .keys
+        .iter()
+        .map(|key| ("static", &key.key, &key.namespace, &key.locations));
+    let patterns = manifest.patterns.iter().map(|pattern| {
+        (
+            "pattern",
+            &pattern.pattern,
+            &pattern.namespace,
+            &pattern.locations,
+        )
+    });
+
+    for (kind, key, namespace, locations) in keys.chain(patterns) {
+        for location in locations {
+            csv.push_str(&format!(
+                "{},{},{},{},{}\n",
+                kind,
+                csv_field(key),
+                csv_field(namespace.as_deref().unwrap_or_default()),
+                csv_field(&location.file),
+                location.line
+            ));
+        }
+    }
+
+    csv
+}
+
+// Fields containing separators or quotes are quoted, quotes are doubled
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use swc_core::common::{sync::Lrc, FileName, SourceMap, GLOBALS};
+    use translation_converter::config::Config;
+
+    use crate::{
+        extract::{csv, manifest, pattern, references, Reference},
+        source::{parse, syntax},
+    };
+
+    fn extract(input: &str) -> Vec<Reference> {
+        extract_with(input, "{}")
+    }
+
+    fn extract_with(input: &str, config: &str) -> Vec<Reference> {
+        GLOBALS.set(&Default::default(), || {
+            let cm: Lrc<SourceMap> = Default::default();
+            let file = cm.new_source_file(
+                FileName::Custom("Foo.tsx".to_string()).into(),
+                input.to_string(),
+            );
+            let source = parse(file, syntax("Foo.tsx".as_ref())).unwrap();
+
+            references(&cm, &source, &Config::parse(config, None))
+        })
+    }
+
+    #[test]
+    fn collects_the_outermost_translations_with_their_line() {
+        let references = extract(
+            "t(l.common.foo.bar);\n<p>{t(l.checkout[isFoo ? l.checkout.foo : l.common.bar])}</p>;",
+        );
+
+        let keys: Vec<(&str, usize)> = references
+            .iter()
+            .map(|reference| (reference.key.as_str(), reference.line))
+            .collect();
+
+        assert_eq!(
+            keys,
+            vec![
+                ("common:foo.bar", 1),
+                ("checkout:${...}", 2),
+                ("checkout:foo", 2),
+                ("common:bar", 2)
+            ]
+        );
+    }
+
+    #[test]
+    fn collects_keys_passed_to_sinks_as_strings() {
+        let references = extract(
+            r#"const { t } = useTranslation("checkout", { keyPrefix: "summary" });
+t("total");
+i18n.t(["errors:specific", `errors:${code}`]);
+<Trans i18nKey="common:welcome" />;
+"#,
+        );
+
+        let keys: Vec<(&str, usize)> = references
+            .iter()
+            .map(|reference| (reference.key.as_str(), reference.line))
+            .collect();
+
+        assert_eq!(
+            keys,
+            vec![
+                ("checkout:summary.total", 2),
+                ("errors:specific", 3),
+                ("errors:${code}", 3),
+                ("common:welcome", 4)
+            ]
+        );
+    }
+
+    #[test]
+    fn resolves_keys_like_the_converter() {
+        let references = extract_with(
+            r#"import checkoutL from "@fullscript/i18n/checkout";
+
+const { common } = l;
+
+t(common.foo);
+t(checkoutL.total);
+t(l.common.form.submit);
+t(l.common.save);
+
+function Foo(common) {
+  return common.bar;
+}
+"#,
+            r#"{
+              "mode": "targeted",
+              "rootImports": { "@fullscript/i18n/checkout": "checkout" },
+              "remap": { "common.form": "forms" },
+              "deprecatedKeys": { "common:save": "common:submit" }
+            }"#,
+        );
+
+        let keys: Vec<&str> = references
+            .iter()
+            .map(|reference| reference.key.as_str())
+            .collect();
+
+        assert_eq!(
+            keys,
+            vec![
+                "common:foo",
+                "checkout:total",
+                "forms:submit",
+                "common:submit"
+            ]
+        );
+    }
+
+    #[test]
+    fn lists_computed_namespaces_as_the_namespace_manifest_does() {
+        let input = "t(l[ns].foo);\nt(l.common.foo);";
+
+        let manifest = manifest(&extract_with(
+            input,
+            r#"{ "namespaceManifest": { "computed": "wildcard" } }"#,
+        ));
+
+        assert_eq!(
+            manifest.namespaces.into_iter().collect::<Vec<_>>(),
+            vec!["*", "common"]
+        );
+    }
+
+    #[test]
+    fn ignores_namespaces_and_other_members() {
+        assert!(extract("Object.keys(l.common); data.currentPatient.name;").is_empty());
+    }
+
+    #[test]
+    fn replaces_interpolated_values_with_wildcards() {
+        assert_eq!(pattern("common:foo.${bar}"), "common:foo.*");
+        assert_eq!(pattern("${ns}:foo.${bar}.baz"), "*:foo.*.baz");
+        assert_eq!(pattern("common:foobar"), "common:foobar");
+    }
+
+    #[test]
+    fn groups_references_by_key() {
+        let manifest = manifest(&extract(
+            "t(l.common.foo);\nt(l.common.foo);\nt(l.checkout.items[count]);\nt(l[ns].foo);",
+        ));
+
+        assert_eq!(
+            manifest.namespaces.into_iter().collect::<Vec<_>>(),
+            vec!["checkout", "common"]
+        );
+        assert_eq!(manifest.keys.len(), 1);
+        assert_eq!(manifest.keys[0].locations.len(), 2);
+        assert_eq!(manifest.patterns[0].pattern, "*:foo");
+        assert_eq!(manifest.patterns[0].namespace, None);
+        assert_eq!(manifest.patterns[1].pattern, "checkout:items.*");
+    }
+
+    #[test]
+    fn writes_a_row_per_reference() {
+        let manifest = manifest(&extract("t(l.common.foo);\nt(l.common.foo[bar]);"));
+
+        assert_eq!(
+            csv(&manifest),
+            "type,key,namespace,file,line\n\
+             static,common:foo,common,Foo.tsx,1\n\
+             pattern,common:foo.*,common,Foo.tsx,2\n"
+        );
+    }
+}
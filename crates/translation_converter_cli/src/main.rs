@@ -0,0 +1,313 @@
+use clap::{Parser, Subcommand, ValueEnum};
+use std::{env, fs, path::PathBuf, process::ExitCode};
+use swc_core::common::{
+    errors::{Handler, HANDLER},
+    sync::Lrc,
+    SourceMap, GLOBALS,
+};
+use translation_converter::{
+    config::{relative_filename, Config},
+    resources::Resources,
+};
+
+mod declarations;
+mod edit;
+mod extract;
+mod migrate;
+mod resources;
+mod reverse;
+mod sinks;
+mod source;
+mod stub;
+mod unused;
+
+/// Tools built on top of the translation converter
+#[derive(Parser)]
+#[command(name = "translation-converter", version)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Lists the translation keys referenced by source files
+    Extract {
+        /// Files or directories to extract keys from
+        #[arg(required = true)]
+        paths: Vec<PathBuf>,
+        #[arg(long, value_enum, default_value_t = Format::Json)]
+        format: Format,
+        /// File the manifest is written to, stdout when missing
+        #[arg(long)]
+        output: Option<PathBuf>,
+        /// JSON file with the options of the plugin, keys are resolved the way the plugin converts them
+        #[arg(long)]
+        config: Option<PathBuf>,
+    },
+    /// Lists the keys of the resources that are never referenced by source files
+    Unused {
+        /// Files or directories referencing the keys
+        #[arg(required = true)]
+        paths: Vec<PathBuf>,
+        /// Directory of the resources of a language, with a JSON file per namespace
+        #[arg(long)]
+        resources: PathBuf,
+        /// Removes the unused keys from the resource files
+        #[arg(long)]
+        prune: bool,
+        /// JSON file with the options of the plugin, keys are resolved the way the plugin converts them
+        #[arg(long)]
+        config: Option<PathBuf>,
+    },
+    /// Converts the translations of source files in place, for packages dropping l for good
+    Migrate {
+        /// Files or directories to migrate
+        #[arg(required = true)]
+        paths: Vec<PathBuf>,
+        /// JSON file with the options of the plugin, ex: { "mode": "targeted" }
+        #[arg(long)]
+        config: Option<PathBuf>,
+    },
+    /// Rewrites the keys passed to translation sinks in source files as l translations
+    Reverse {
+        /// Files or directories to rewrite
+        #[arg(required = true)]
+        paths: Vec<PathBuf>,
+        /// JSON file with the options of the plugin, the sinks option lists the calls and attributes rewritten
+        #[arg(long)]
+        config: Option<PathBuf>,
+        /// Module l is imported from when a file doesn't import it yet, ex: @fullscript/i18n
+        #[arg(long)]
+        import: String,
+    },
+    /// Generates the TypeScript declarations of l from the resources of the source locale
+    Types {
+        /// Directory of the resources of the source locale, with a JSON file per namespace
+        #[arg(long)]
+        resources: PathBuf,
+        /// File the declarations are written to, ex: l.d.ts, stdout when missing
+        #[arg(long)]
+        output: Option<PathBuf>,
+    },
+    /// Generates a module exporting l where every translation is its key, for code the plugin doesn't run on
+    Stub {
+        /// Directory of the resources of the source locale, with a JSON file per namespace
+        #[arg(long)]
+        resources: PathBuf,
+        #[arg(long, value_enum, default_value_t = ModuleFormat::Esm)]
+        format: ModuleFormat,
+        /// File the module is written to, ex: l.js, stdout when missing
+        #[arg(long)]
+        output: Option<PathBuf>,
+    },
+}
+
+#[derive(Clone, ValueEnum)]
+enum Format {
+    Json,
+    Csv,
+}
+
+#[derive(Clone, ValueEnum)]
+enum ModuleFormat {
+    Esm,
+    Commonjs,
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    match GLOBALS.set(&Default::default(), || run(cli.command)) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("translation-converter: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(command: Command) -> Result<(), String> {
+    match command {
+        Command::Extract {
+            paths,
+            format,
+            output,
+            config,
+        } => {
+            let config = read_config(config)?;
+            let references = references(&paths, &config)?;
+            let manifest = extract::manifest(&references);
+
+            let manifest = match format {
+                Format::Json => serde_json::to_string_pretty(&manifest)
+                    .map_err(|err| format!("unable to serialize the manifest: {err}"))?,
+                Format::Csv => extract::csv(&manifest),
+            };
+
+            write(output, &manifest)
+        }
+        Command::Unused {
+            paths,
+            resources: dir,
+            prune,
+            config,
+        } => {
+            let config = read_config(config)?;
+            let usage = unused::Usage::new(&references(&paths, &config)?);
+
+            for (namespace, mut resource) in Resources::load(&dir)?.into_namespaces() {
+                // common:legacy (3 keys)
+                for unused in unused::unused(&namespace, &resource, &usage) {
+                    match unused.keys {
+                        Some(keys) => println!("{} ({keys} keys)", unused.key),
+                        None => println!("{}", unused.key),
+                    }
+                }
+
+                if prune {
+                    unused::prune(&namespace, &mut resource, &usage);
+                    let path = resources::write(&dir, &namespace, &resource)?;
+                    eprintln!("pruned {}", path.display());
+                }
+            }
+
+            Ok(())
+        }
+        Command::Migrate { paths, config } => {
+            let config = read_config(config)?;
+            let cwd = env::current_dir().ok();
+            let cwd = cwd.as_ref().and_then(|cwd| cwd.to_str());
+
+            let cm: Lrc<SourceMap> = Default::default();
+            let handler =
+                Handler::with_emitter_writer(Box::new(std::io::stderr()), Some(cm.clone()));
+            let mut failed = 0;
+
+            for path in source::files(&paths)? {
+                if !config.should_transform(relative_filename(&path.to_string_lossy(), cwd)) {
+                    continue;
+                }
+
+                let source = source::load(&cm, &path)?;
+                let errors = handler.err_count();
+                let migrated =
+                    HANDLER.set(&handler, || migrate::migrate(&cm, source, config.clone()));
+
+                // files with invalid translations are left for a manual migration
+                if handler.err_count() > errors {
+                    failed += 1;
+                    continue;
+                }
+
+                let migrated = match migrated {
+                    Ok(migrated) => migrated,
+                    Err(err) => {
+                        eprintln!("unable to migrate {}: {err}", path.display());
+                        failed += 1;
+                        continue;
+                    }
+                };
+
+                if let Some(migrated) = migrated {
+                    fs::write(&path, migrated)
+                        .map_err(|err| format!("unable to write {}: {err}", path.display()))?;
+                    eprintln!("migrated {}", path.display());
+                }
+            }
+
+            match failed {
+                0 => Ok(()),
+                _ => Err(format!(
+                    "{failed} files were not migrated because of errors"
+                )),
+            }
+        }
+        Command::Reverse {
+            paths,
+            config,
+            import,
+        } => {
+            let config = read_config(config)?;
+            let cwd = env::current_dir().ok();
+            let cwd = cwd.as_ref().and_then(|cwd| cwd.to_str());
+
+            let cm: Lrc<SourceMap> = Default::default();
+
+            for path in source::files(&paths)? {
+                if !config.should_transform(relative_filename(&path.to_string_lossy(), cwd)) {
+                    continue;
+                }
+
+                let source = source::load(&cm, &path)?;
+
+                let reversed = reverse::reverse(&cm, &source, &config, &import)
+                    .map_err(|err| format!("unable to rewrite {}: {err}", path.display()))?;
+
+                if let Some(reversed) = reversed {
+                    fs::write(&path, reversed)
+                        .map_err(|err| format!("unable to write {}: {err}", path.display()))?;
+                    eprintln!("rewrote {}", path.display());
+                }
+            }
+
+            Ok(())
+        }
+        Command::Types { resources, output } => write(
+            output,
+            &declarations::declarations(Resources::load(&resources)?.namespaces()),
+        ),
+        Command::Stub {
+            resources,
+            format,
+            output,
+        } => write(
+            output,
+            &stub::stub(Resources::load(&resources)?.namespaces(), format),
+        ),
+    }
+}
+
+/// Options of the plugin, read from a JSON file and parsed once for every file
+fn read_config(path: Option<PathBuf>) -> Result<Config, String> {
+    let Some(path) = path else {
+        return Ok(Config::default());
+    };
+
+    let config = fs::read_to_string(&path)
+        .map_err(|err| format!("unable to read {}: {err}", path.display()))?;
+
+    Config::try_parse(&config, None).map_err(|err| format!("{}: {err}", path.display()))
+}
+
+/// Translations referenced by the source files of the given paths, files the plugin doesn't transform are skipped
+fn references(paths: &[PathBuf], config: &Config) -> Result<Vec<extract::Reference>, String> {
+    let cwd = env::current_dir().ok();
+    let cwd = cwd.as_ref().and_then(|cwd| cwd.to_str());
+
+    let cm: Lrc<SourceMap> = Default::default();
+    let mut references = vec![];
+
+    for path in source::files(paths)? {
+        if !config.should_transform(relative_filename(&path.to_string_lossy(), cwd)) {
+            continue;
+        }
+
+        let source = source::load(&cm, &path)?;
+        references.extend(extract::references(&cm, &source, config));
+    }
+
+    Ok(references)
+}
+
+/// Writes the output to a file, or to stdout when no file is given
+fn write(output: Option<PathBuf>, content: &str) -> Result<(), String> {
+    match output {
+        Some(path) => fs::write(&path, content)
+            .map_err(|err| format!("unable to write {}: {err}", path.display())),
+        None => {
+            println!("{}", content.trim_end());
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,558 @@
+use std::{
+    collections::{HashMap, HashSet},
+    mem,
+};
+use swc_core::{
+    common::{
+        comments::{Comments, SingleThreadedComments},
+        sync::Lrc,
+        BytePos, EqIgnoreSpan, SourceMap, Span, Spanned,
+    },
+    ecma::codegen::{to_code_default, Node},
+};
+use swc_ecma_ast::{
+    CallExpr, Expr, ImportDecl, ImportSpecifier, JSXExprContainer, MemberExpr, Module, ModuleDecl,
+    ModuleItem, Program, VarDecl,
+};
+use swc_ecma_visit::{Visit, VisitMutWith, VisitWith};
+use translation_converter::{builders::utils::prologue_len, config::Config, TranslationConverter};
+
+use crate::{
+    edit::{apply, Edit},
+    source::Source,
+};
+
+/// Converts the translations of a source file for good, only the converted parts of the source are rewritten
+/// so its formatting and comments are left untouched
+/// returns None when the file has nothing to convert, and an error when the rewritten parts overlap
+///
+/// # Examples
+/// ```text
+/// // import { l } from "@fullscript/i18n";
+/// // const label = t(l.common.save);
+/// let migrated = migrate(&cm, source, config)?;
+///
+/// assert_eq!(migrated, Some("const label = t(\"common:save\");\n".to_string()));
+/// ```
+pub fn migrate(
+    cm: &Lrc<SourceMap>,
+    source: Source,
+    config: Config,
+) -> Result<Option<String>, String> {
+    let original = Original::new(&source.module);
+
+    let mut program = Program::Module(source.module);
+    program.visit_mut_with(&mut TranslationConverter::new(
+        source.comments.clone(),
+        config,
+    ));
+    let Program::Module(module) = program else {
+        return Ok(None);
+    };
+
+    let mut collector = EditCollector {
+        cm,
+        comments: &source.comments,
+        original: &original,
+        var_decls: HashSet::new(),
+        dirty: false,
+        edits: vec![],
+    };
+
+    module.visit_with(&mut collector);
+    collector.remove_var_decls(&source.file.src);
+    collector.remove_dead_imports(&module, &source.file.src);
+
+    let edits = collector.edits;
+
+    if edits.is_empty() {
+        return Ok(None);
+    }
+
+    apply(&source.file.src, source.file.start_pos, edits).map(Some)
+}
+
+/// Parts of the source the converter may replace or remove, indexed by span
+struct Original {
+    members: HashSet<Span>,
+    jsx_expr_containers: HashSet<Span>,
+    var_decls: HashMap<Span, VarDecl>,
+    /// Number of references of each identifier, imports aside
+    references: HashMap<String, usize>,
+}
+
+impl Original {
+    fn new(module: &Module) -> Original {
+        let mut original = Original {
+            members: HashSet::new(),
+            jsx_expr_containers: HashSet::new(),
+            var_decls: HashMap::new(),
+            references: HashMap::new(),
+        };
+
+        module.visit_with(&mut original);
+        original.references = references(module);
+
+        original
+    }
+}
+
+impl Visit for Original {
+    fn visit_member_expr(&mut self, member: &MemberExpr) {
+        self.members.insert(member.span);
+        member.visit_children_with(self);
+    }
+
+    fn visit_jsx_expr_container(&mut self, jsx_expr_cont: &JSXExprContainer) {
+        self.jsx_expr_containers.insert(jsx_expr_cont.span);
+        jsx_expr_cont.visit_children_with(self);
+    }
+
+    fn visit_var_decl(&mut self, var_decl: &VarDecl) {
+        self.var_decls.insert(var_decl.span, var_decl.clone());
+        var_decl.visit_children_with(self);
+    }
+}
+
+/// Counts the references of each identifier, the bindings of imports are not references
+fn references(module: &Module) -> HashMap<String, usize> {
+    let mut counter = ReferenceCounter::default();
+    module.visit_with(&mut counter);
+
+    counter.references
+}
+
+#[derive(Default)]
+struct ReferenceCounter {
+    references: HashMap<String, usize>,
+}
+
+impl Visit for ReferenceCounter {
+    fn visit_import_decl(&mut self, _: &ImportDecl) {}
+
+    fn visit_ident(&mut self, ident: &swc_ecma_ast::Ident) {
+        *self.references.entry(ident.sym.to_string()).or_default() += 1;
+    }
+}
+
+/// Compares the converted module with the original one to find the parts of the source to rewrite
+struct EditCollector<'a> {
+    cm: &'a Lrc<SourceMap>,
+    comments: &'a SingleThreadedComments,
+    original: &'a Original,
+    /// Spans of the variable declarations left by the converter
+    var_decls: HashSet<Span>,
+    /// Whether a node created by the converter was found, the closest original node containing it is rewritten
+    dirty: bool,
+    edits: Vec<Edit>,
+}
+
+impl EditCollector<'_> {
+    // Edits within the replaced source are dropped, the replacement already contains them
+    fn replace(&mut self, lo: BytePos, hi: BytePos, text: String) {
+        self.edits.retain(|edit| {
+            if edit.lo == edit.hi {
+                edit.lo <= lo || edit.lo >= hi
+            } else {
+                edit.lo < lo || edit.hi > hi
+            }
+        });
+        self.edits.push(Edit { lo, hi, text });
+    }
+
+    fn is_replaced(&self, span: Span) -> bool {
+        self.edits
+            .iter()
+            .any(|edit| edit.lo <= span.lo && span.hi <= edit.hi && edit.lo < edit.hi)
+    }
+
+    // Comments around the node are still part of the source, only the ones within it are generated
+    // comments added by the converter have no span, ex: t("common:foobar" /* l.common.foobar */)
+    fn emit<N: Node>(&self, node: &N) -> String {
+        let span = node.span();
+        let leading = self.comments.take_leading(span.lo);
+        let trailing = self.comments.take_trailing(span.hi);
+
+        if let Some(trailing) = &trailing {
+            self.comments.add_trailing_comments(
+                span.hi,
+                trailing
+                    .iter()
+                    .filter(|comment| comment.span.is_dummy())
+                    .cloned()
+                    .collect(),
+            );
+        }
+
+        let code = to_code_default(self.cm.clone(), Some(self.comments), node);
+
+        self.comments.take_trailing(span.hi);
+
+        if let Some(leading) = leading {
+            self.comments.add_leading_comments(span.lo, leading);
+        }
+
+        if let Some(trailing) = trailing {
+            self.comments.add_trailing_comments(span.hi, trailing);
+        }
+
+        code.trim_end().to_string()
+    }
+
+    // Arrays created by the converter are emitted on a single line, ex: ["common", "checkout"]
+    fn emit_inline(&self, expr: &Expr) -> String {
+        let Expr::Array(array) = expr else {
+            return self.emit(expr);
+        };
+
+        let elems: Vec<String> = array
+            .elems
+            .iter()
+            .flatten()
+            .map(|elem| self.emit_inline(&elem.expr))
+            .collect();
+
+        format!("[{}]", elems.join(", "))
+    }
+
+    // Items added by the converter are inserted next to the closest original item
+    // import i18n from "i18next"; is inserted before the first item, export const __i18nNamespaces after the last one
+    fn insert(&mut self, module: &Module, index: usize) {
+        let items = &module.body;
+        let text = self.emit(&items[index]);
+
+        // "use client" directives need to stay first, nothing is inserted before them
+        let next = items[index.max(prologue_len(module))..]
+            .iter()
+            .map(Spanned::span)
+            .find(|span| !span.is_dummy());
+        let previous = items[..index]
+            .iter()
+            .rev()
+            .map(Spanned::span)
+            .find(|span| !span.is_dummy());
+
+        let (pos, text) = match (next, previous) {
+            (Some(next), _) => (next.lo, format!("{text}\n")),
+            (None, Some(previous)) => (previous.hi, format!("\n{text}")),
+            (None, None) => (BytePos::DUMMY, text),
+        };
+
+        self.edits.push(Edit {
+            lo: pos,
+            hi: pos,
+            text,
+        });
+    }
+
+    // Declarations of aliases left without references are removed by the converter
+    // const { save } = l.common;
+    fn remove_var_decls(&mut self, src: &str) {
+        let removed: Vec<Span> = self
+            .original
+            .var_decls
+            .keys()
+            .filter(|span| !self.var_decls.contains(span) && !self.is_replaced(**span))
+            .copied()
+            .collect();
+
+        for span in removed {
+            self.remove(span, src);
+        }
+    }
+
+    // l is left without references once its translations are converted
+    // import { l, Trans } from "@fullscript/i18n"; -> import { Trans } from "@fullscript/i18n";
+    fn remove_dead_imports(&mut self, module: &Module, src: &str) {
+        let references = references(module);
+
+        let is_dead = |specifier: &ImportSpecifier| {
+            let local = match specifier {
+                ImportSpecifier::Named(named) => &named.local,
+                ImportSpecifier::Default(default) => &default.local,
+                ImportSpecifier::Namespace(namespace) => &namespace.local,
+            };
+
+            self.original.references.contains_key(local.sym.as_str())
+                && !references.contains_key(local.sym.as_str())
+        };
+
+        for item in &module.body {
+            let ModuleItem::ModuleDecl(ModuleDecl::Import(import)) = item else {
+                continue;
+            };
+
+            if import.span.is_dummy() || !import.specifiers.iter().any(is_dead) {
+                continue;
+            }
+
+            if import.specifiers.iter().all(is_dead) {
+                self.remove(import.span, src);
+                continue;
+            }
+
+            let mut import = import.clone();
+            import.specifiers.retain(|specifier| !is_dead(specifier));
+
+            let text = self.emit(&import);
+            self.replace(import.span.lo, import.span.hi, text);
+        }
+    }
+
+    // The line is removed along with the node when nothing else is on it
+    // so is the blank line following it when it starts the file or follows another blank line
+    // unless an item is inserted in its place, ex: import i18n from "i18next"; replacing the l import
+    fn remove(&mut self, span: Span, src: &str) {
+        let replaced = self
+            .edits
+            .iter()
+            .any(|edit| edit.lo == span.lo && edit.hi == span.lo);
+
+        let start_pos = self.cm.lookup_byte_offset(span.lo).sf.start_pos;
+        let lo = (span.lo - start_pos).0 as usize;
+        let hi = (span.hi - start_pos).0 as usize;
+
+        let before = src[..lo].trim_end_matches([' ', '\t']);
+        let after = src[hi..].trim_start_matches([' ', '\t']);
+
+        let (lo, hi) = match (
+            before.is_empty() || before.ends_with('\n'),
+            after.strip_prefix('\n'),
+        ) {
+            (true, Some(rest)) => {
+                let rest = match !replaced && (before.is_empty() || before.ends_with("\n\n")) {
+                    true => rest.strip_prefix('\n').unwrap_or(rest),
+                    false => rest,
+                };
+
+                (before.len(), src.len() - rest.len())
+            }
+            (true, None) if after.is_empty() => (before.len(), src.len()),
+            _ => (lo, hi),
+        };
+
+        self.replace(
+            start_pos + BytePos(lo as u32),
+            start_pos + BytePos(hi as u32),
+            String::new(),
+        );
+    }
+}
+
+impl Visit for EditCollector<'_> {
+    fn visit_module(&mut self, module: &Module) {
+        for (index, item) in module.body.iter().enumerate() {
+            if item.span().is_dummy() {
+                self.insert(module, index);
+            } else {
+                item.visit_with(self);
+                self.dirty = false;
+            }
+        }
+    }
+
+    fn visit_expr(&mut self, expr: &Expr) {
+        let span = expr.span();
+
+        if span.is_dummy() {
+            self.dirty = true;
+            return;
+        }
+
+        // <p>{l.common.foobar}</p> -> <p>{t("common:foobar")}</p>, the converted translation takes the span of its container
+        if self.original.jsx_expr_containers.contains(&span) {
+            let text = self.emit(expr);
+            return self.replace(span.lo + BytePos(1), span.hi - BytePos(1), text);
+        }
+
+        if self.original.members.contains(&span) && !expr.is_member() {
+            let text = self.emit(expr);
+            return self.replace(span.lo, span.hi, text);
+        }
+
+        let dirty = mem::replace(&mut self.dirty, false);
+        expr.visit_children_with(self);
+
+        // const { t } = useTranslation(); -> const { t } = useTranslation(["common"]);
+        if self.dirty {
+            let text = self.emit(expr);
+            self.replace(span.lo, span.hi, text);
+        }
+
+        self.dirty = dirty;
+    }
+
+    // Arguments added by the converter are inserted before the closing parenthesis, the call is left as is
+    // const { t } = useTranslation(); -> const { t } = useTranslation(["common"]);
+    fn visit_call_expr(&mut self, call_expr: &CallExpr) {
+        call_expr.callee.visit_with(self);
+        call_expr.type_args.visit_with(self);
+
+        let mut has_args = false;
+
+        for arg in &call_expr.args {
+            if !arg.expr.span().is_dummy() {
+                arg.visit_with(self);
+                has_args = true;
+                continue;
+            }
+
+            let text = self.emit_inline(&arg.expr);
+            let pos = call_expr.span.hi - BytePos(1);
+
+            self.edits.push(Edit {
+                lo: pos,
+                hi: pos,
+                text: match has_args {
+                    true => format!(", {text}"),
+                    false => text,
+                },
+            });
+            has_args = true;
+        }
+    }
+
+    fn visit_var_decl(&mut self, var_decl: &VarDecl) {
+        self.var_decls.insert(var_decl.span);
+
+        let Some(original) = self.original.var_decls.get(&var_decl.span) else {
+            return var_decl.visit_children_with(self);
+        };
+
+        // const { save, cancel } = l.common; -> const { cancel } = l.common;
+        let is_trimmed = original.decls.len() != var_decl.decls.len()
+            || original
+                .decls
+                .iter()
+                .zip(&var_decl.decls)
+                .any(|(original, declarator)| !original.name.eq_ignore_span(&declarator.name));
+
+        if !is_trimmed {
+            return var_decl.visit_children_with(self);
+        }
+
+        let text = self.emit(var_decl);
+        self.replace(var_decl.span.lo, var_decl.span.hi, text);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use swc_core::common::{
+        errors::{Handler, HANDLER},
+        sync::Lrc,
+        FileName, SourceMap, GLOBALS,
+    };
+    use translation_converter::config::Config;
+
+    use crate::{
+        migrate::migrate,
+        source::{parse, syntax},
+    };
+
+    fn run(input: &str, config: &str) -> Option<String> {
+        GLOBALS.set(&Default::default(), || {
+            let cm: Lrc<SourceMap> = Default::default();
+            let handler = Handler::with_emitter_writer(Box::new(std::io::sink()), Some(cm.clone()));
+            let file = cm.new_source_file(
+                FileName::Custom("Foo.tsx".to_string()).into(),
+                input.to_string(),
+            );
+            let source = parse(file, syntax("Foo.tsx".as_ref())).unwrap();
+
+            HANDLER.set(&handler, || {
+                migrate(&cm, source, Config::parse(config, None)).unwrap()
+            })
+        })
+    }
+
+    #[test]
+    fn rewrites_translations_and_removes_the_l_import() {
+        let input = r#"import { l } from "@fullscript/i18n";
+import { useTranslation } from "react-i18next";
+
+// Displays the save button
+export const Foo = () => {
+  const { t } = useTranslation();
+
+  return <Button   label={t(l.common.save)} />;
+};
+"#;
+
+        assert_eq!(
+            run(input, "{}").unwrap(),
+            r#"import { useTranslation } from "react-i18next";
+
+// Displays the save button
+export const Foo = () => {
+  const { t } = useTranslation();
+
+  return <Button   label={t("common:save")} />;
+};
+"#
+        );
+    }
+
+    #[test]
+    fn keeps_the_other_specifiers_of_the_l_import() {
+        let input =
+            "import { l, Trans } from '@fullscript/i18n';\n\n<Trans i18nKey={l.common.save} />;\n";
+
+        assert_eq!(
+            run(input, "{}").unwrap(),
+            "import { Trans } from '@fullscript/i18n';\n\n<Trans i18nKey={\"common:save\"} />;\n"
+        );
+    }
+
+    #[test]
+    fn removes_aliases_and_rewrites_nodes_added_by_the_converter() {
+        let input = r#"import { l } from "@fullscript/i18n";
+
+function Foo() {
+  const { t } = useTranslation();
+  const common = l.common;
+
+  return <p>{t(common.save)}</p>;
+}
+"#;
+
+        assert_eq!(
+            run(input, r#"{ "preloadNamespaces": true }"#).unwrap(),
+            r#"function Foo() {
+  const { t } = useTranslation(["common"]);
+
+  return <p>{t("common:save")}</p>;
+}
+"#
+        );
+    }
+
+    #[test]
+    fn inserts_the_import_of_the_translate_function() {
+        let input = "import { l } from \"@fullscript/i18n\";\n\nexport const Foo = () => <p>{l.common.save}</p>;\n";
+        let config =
+            r#"{ "translate": { "callee": "i18n.t", "import": { "source": "i18next" } } }"#;
+
+        assert_eq!(
+            run(input, config).unwrap(),
+            "import i18n from \"i18next\";\n\nexport const Foo = () => <p>{i18n.t(\"common:save\")}</p>;\n"
+        );
+    }
+
+    #[test]
+    fn inserts_the_import_of_the_translate_function_after_directives() {
+        let input = "\"use client\";\n\nexport const Foo = () => <p>{l.common.save}</p>;\n";
+        let config =
+            r#"{ "translate": { "callee": "i18n.t", "import": { "source": "i18next" } } }"#;
+
+        assert_eq!(
+            run(input, config).unwrap(),
+            "\"use client\";\n\nimport i18n from \"i18next\";\nexport const Foo = () => <p>{i18n.t(\"common:save\")}</p>;\n"
+        );
+    }
+
+    #[test]
+    fn leaves_files_without_translations_untouched() {
+        assert_eq!(run("const label = t(\"common:save\");\n", "{}"), None);
+    }
+}
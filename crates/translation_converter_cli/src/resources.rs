@@ -0,0 +1,108 @@
+use serde_json::Value;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+use translation_converter::resources::plural_key;
+
+/// Writes the resource of a namespace back, formatted with 2 spaces like most translation tools
+pub fn write(dir: &Path, namespace: &str, value: &Value) -> Result<PathBuf, String> {
+    let path = dir.join(format!("{namespace}.json"));
+
+    let resource = serde_json::to_string_pretty(value)
+        .map_err(|err| format!("unable to serialize {}: {err}", path.display()))?;
+
+    fs::write(&path, format!("{resource}\n"))
+        .map_err(|err| format!("unable to write {}: {err}", path.display()))?;
+
+    Ok(path)
+}
+
+/// A translation of l, the key it is converted into or an object of translations
+pub enum Translation {
+    Key(String),
+    Object(Vec<(String, Translation)>),
+}
+
+/// The translations of l.<namespace> built from the resource of the namespace
+/// plural forms are collapsed into the key they belong to, ex: items_one, items_other -> l.common.items
+pub fn translations(namespace: &str, resource: &Value) -> Translation {
+    object(namespace, None, resource)
+}
+
+fn object(namespace: &str, path: Option<&str>, value: &Value) -> Translation {
+    let mut translations: Vec<(String, Translation)> = vec![];
+
+    let Value::Object(properties) = value else {
+        return Translation::Object(translations);
+    };
+
+    for (property, value) in properties {
+        let property = match value {
+            Value::Object(_) => property.as_str(),
+            _ => plural_key(property).unwrap_or(property),
+        };
+
+        if translations.iter().any(|(name, _)| name == property) {
+            continue;
+        }
+
+        let key = join(namespace, path, property);
+
+        let translation = match value {
+            Value::Object(_) => object(namespace, Some(&key), value),
+            _ => Translation::Key(key),
+        };
+
+        translations.push((property.to_string(), translation));
+    }
+
+    Translation::Object(translations)
+}
+
+/// Joins the path of a resource with one of its properties
+/// ex: ("common", None, "save") -> "common:save", ("common", Some("common:form"), "submit") -> "common:form.submit"
+pub fn join(namespace: &str, path: Option<&str>, property: &str) -> String {
+    match path {
+        Some(path) => format!("{path}.{property}"),
+        None => format!("{namespace}:{property}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::resources::{join, translations, Translation};
+
+    #[test]
+    fn joins_namespaces_and_properties() {
+        assert_eq!(join("common", None, "save"), "common:save");
+        assert_eq!(
+            join("common", Some("common:form"), "submit"),
+            "common:form.submit"
+        );
+    }
+
+    #[test]
+    fn collapses_plural_forms_into_their_key() {
+        let resource = json!({
+            "items_one": "{{count}} item",
+            "items_other": "{{count}} items",
+            "form": { "submit_one": { "label": "Submit" } }
+        });
+
+        let Translation::Object(translations) = translations("common", &resource) else {
+            unreachable!();
+        };
+
+        let names: Vec<&str> = translations.iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(names, ["items", "form"]);
+
+        let Translation::Object(form) = &translations[1].1 else {
+            unreachable!();
+        };
+
+        assert_eq!(form[0].0, "submit_one");
+    }
+}
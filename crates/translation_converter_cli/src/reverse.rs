@@ -0,0 +1,241 @@
+use swc_core::{
+    common::{comments::Comments, sync::Lrc, BytePos, SourceMap, Spanned},
+    ecma::codegen::to_code_default,
+};
+use swc_ecma_ast::{ModuleDecl, ModuleItem};
+use translation_converter::{
+    builders::utils::{module_binds, prologue_len},
+    config::Config,
+    scope,
+};
+
+use crate::{
+    edit::{apply, Edit},
+    sinks::sink_keys,
+    source::Source,
+};
+
+/// Rewrites the keys passed to translation sinks as l translations, the inverse of the conversion
+/// l is imported from import_source when the file doesn't import it yet
+/// returns None when the file has no key to rewrite, and an error when the rewritten keys overlap
+///
+/// # Examples
+/// ```text
+/// // t("common:save"); t(`errors:${code}`); <Trans i18nKey="common:welcome" />
+/// let reversed = reverse(&cm, &source, &config, "@fullscript/i18n")?;
+///
+/// // import { l } from "@fullscript/i18n";
+/// // t(l.common.save); t(l.errors[code]); <Trans i18nKey={l.common.welcome} />
+/// ```
+pub fn reverse(
+    cm: &Lrc<SourceMap>,
+    source: &Source,
+    config: &Config,
+    import_source: &str,
+) -> Result<Option<String>, String> {
+    let mut edits: Vec<Edit> = sink_keys(source, config)
+        .into_iter()
+        .filter_map(|sink_key| {
+            // The default namespace and key prefix the converter strips are added back
+            // const { t } = useTranslation("checkout", { keyPrefix: "summary" }); t("total") -> t(l.checkout.summary.total)
+            let member =
+                scope::key_translation(&sink_key.key, sink_key.translate_function.as_ref())?;
+            let member = to_code_default(cm.clone(), None, &member);
+
+            let span = sink_key.key.span();
+
+            Some(Edit {
+                lo: span.lo,
+                hi: span.hi,
+                text: if sink_key.attribute {
+                    format!("{{{member}}}")
+                } else {
+                    member
+                },
+            })
+        })
+        .collect();
+
+    if edits.is_empty() {
+        return Ok(None);
+    }
+
+    // "use client" directives need to stay first, l is imported along with the other imports
+    if !module_binds(&source.module, "l") {
+        let first_import = source.module.body.iter().find_map(|item| match item {
+            ModuleItem::ModuleDecl(ModuleDecl::Import(import)) => Some(import.span.lo),
+            _ => None,
+        });
+        // comments leading the first statement stay attached to it, ex: // translation-converter-disable-next-line
+        let first_statement = source
+            .module
+            .body
+            .get(prologue_len(&source.module))
+            .map(|item| {
+                let lo = item.span().lo;
+
+                source
+                    .comments
+                    .get_leading(lo)
+                    .and_then(|comments| comments.first().map(|comment| comment.span.lo))
+                    .unwrap_or(lo)
+            });
+        let pos = first_import.or(first_statement).unwrap_or(BytePos::DUMMY);
+
+        edits.push(Edit {
+            lo: pos,
+            hi: pos,
+            text: format!("import {{ l }} from \"{import_source}\";\n"),
+        });
+    }
+
+    apply(&source.file.src, source.file.start_pos, edits).map(Some)
+}
+
+#[cfg(test)]
+mod tests {
+    use swc_core::common::{sync::Lrc, FileName, SourceMap, GLOBALS};
+    use translation_converter::config::Config;
+
+    use crate::{
+        reverse::reverse,
+        source::{parse, syntax},
+    };
+
+    fn run(input: &str) -> Option<String> {
+        GLOBALS.set(&Default::default(), || {
+            let cm: Lrc<SourceMap> = Default::default();
+            let file = cm.new_source_file(
+                FileName::Custom("Foo.tsx".to_string()).into(),
+                input.to_string(),
+            );
+            let source = parse(file, syntax("Foo.tsx".as_ref())).unwrap();
+
+            reverse(&cm, &source, &Config::parse("{}", None), "@fullscript/i18n").unwrap()
+        })
+    }
+
+    #[test]
+    fn rewrites_keys_passed_to_sinks_and_imports_l() {
+        let input = r#""use client";
+import { Trans } from "react-i18next";
+
+t("common:save");
+i18n.t('errors:payment.declined', { count });
+t(`errors:${code}.title`);
+<Trans i18nKey="common:welcome" />;
+"#;
+
+        assert_eq!(
+            run(input).unwrap(),
+            r#""use client";
+import { l } from "@fullscript/i18n";
+import { Trans } from "react-i18next";
+
+t(l.common.save);
+i18n.t(l.errors.payment.declined, { count });
+t(l.errors[code].title);
+<Trans i18nKey={l.common.welcome} />;
+"#
+        );
+    }
+
+    #[test]
+    fn adds_back_the_namespace_and_key_prefix_of_t_functions() {
+        let input = r#"import { l } from "@fullscript/i18n";
+
+function Summary() {
+  const { t } = useTranslation(["checkout", "common"], { keyPrefix: "summary" });
+
+  return [t("total"), t("common:save"), t(["vat", "tax"])];
+}
+"#;
+
+        assert_eq!(
+            run(input).unwrap(),
+            r#"import { l } from "@fullscript/i18n";
+
+function Summary() {
+  const { t } = useTranslation(["checkout", "common"], { keyPrefix: "summary" });
+
+  return [t(l.checkout.summary.total), t(l.common.summary.save), t([l.checkout.summary.vat, l.checkout.summary.tax])];
+}
+"#
+        );
+    }
+
+    #[test]
+    fn resolves_t_functions_like_the_converter() {
+        let input = r#"import { l } from "@fullscript/i18n";
+
+function Checkout({ t }) {
+  if (open) {
+    const { t } = useTranslation("cart");
+    t("total");
+  }
+
+  return t("title");
+}
+
+export default withTranslation("checkout")(Checkout);
+"#;
+
+        assert_eq!(
+            run(input).unwrap(),
+            r#"import { l } from "@fullscript/i18n";
+
+function Checkout({ t }) {
+  if (open) {
+    const { t } = useTranslation("cart");
+    t(l.cart.total);
+  }
+
+  return t(l.checkout.title);
+}
+
+export default withTranslation("checkout")(Checkout);
+"#
+        );
+    }
+
+    #[test]
+    fn leaves_keys_that_are_not_identifiers_untouched() {
+        assert_eq!(
+            run(r#"t("common:foo-bar"); t(`common:foo${bar}`); t("save"); label("common:save");"#),
+            None
+        );
+    }
+
+    #[test]
+    fn leaves_keys_disabled_by_pragmas_untouched() {
+        assert_eq!(
+            run("// translation-converter-disable\nt(\"common:save\");\n"),
+            None
+        );
+
+        assert_eq!(
+            run(r#"// translation-converter-disable-next-line
+t("common:save");
+t(/* translation-converter-ignore */ "common:cancel");
+<Trans i18nKey={/* translation-converter-ignore */ "common:welcome"} />;
+t("common:submit");
+"#)
+            .unwrap(),
+            r#"import { l } from "@fullscript/i18n";
+// translation-converter-disable-next-line
+t("common:save");
+t(/* translation-converter-ignore */ "common:cancel");
+<Trans i18nKey={/* translation-converter-ignore */ "common:welcome"} />;
+t(l.common.submit);
+"#
+        );
+    }
+
+    #[test]
+    fn imports_l_after_directives() {
+        assert_eq!(
+            run("\"use client\";\n\nt(\"common:save\");\n").unwrap(),
+            "\"use client\";\n\nimport { l } from \"@fullscript/i18n\";\nt(l.common.save);\n"
+        );
+    }
+}
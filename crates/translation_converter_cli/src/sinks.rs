@@ -0,0 +1,234 @@
+use swc_core::common::{comments::SingleThreadedComments, BytePos, Span, Spanned};
+use swc_ecma_ast::{
+    ArrowExpr, BlockStmt, CallExpr, ClassDecl, Expr, FnDecl, Function, JSXAttrName,
+    JSXAttrOrSpread, JSXAttrValue, JSXExpr, JSXOpeningElement, Lit, ModuleItem, PropOrSpread, Stmt,
+    VarDeclarator,
+};
+use swc_ecma_visit::{Visit, VisitWith};
+use translation_converter::{
+    builders::utils::{callee_path, jsx_element_name, with_translation_components},
+    config::Config,
+    pragmas,
+    scope::{self, Scopes, TranslateFunction},
+};
+
+use crate::source::Source;
+
+/// A string or template key passed to a translation sink, ex: t("common:save"), <Trans i18nKey="common:welcome" />
+pub struct SinkKey {
+    pub key: Expr,
+    /// The t function the key is given to, its namespace and key prefix apply to the key
+    pub translate_function: Option<TranslateFunction>,
+    /// Whether the key is the string value of a JSX attribute, ex: <Trans i18nKey="common:welcome" />
+    pub attribute: bool,
+}
+
+/// Collects the keys passed to translation sinks as strings or templates, in source order
+/// sinks are resolved like the converter does, see scope::Scopes
+/// the pragmas of the converter apply, ex: // translation-converter-disable-next-line
+pub fn sink_keys(source: &Source, config: &Config) -> Vec<SinkKey> {
+    // Leading comments of a file are attached to its first token
+    let first_item_lo = source.module.body.first().map(|item| item.span().lo);
+
+    let disabled = [Some(source.module.span.lo), first_item_lo]
+        .into_iter()
+        .flatten()
+        .any(|pos| pragmas::has_leading_pragma(&source.comments, pos, pragmas::DISABLE));
+
+    if disabled {
+        return vec![];
+    }
+
+    let mut collector = SinkKeyCollector {
+        comments: &source.comments,
+        config,
+        scopes: Scopes::with_components(with_translation_components(&source.module)),
+        keys: vec![],
+    };
+
+    source.module.visit_with(&mut collector);
+
+    collector.keys
+}
+
+struct SinkKeyCollector<'a> {
+    comments: &'a SingleThreadedComments,
+    config: &'a Config,
+    scopes: Scopes,
+    keys: Vec<SinkKey>,
+}
+
+impl SinkKeyCollector<'_> {
+    // t(/* translation-converter-ignore */ "common:save")
+    fn push(&mut self, key: &Expr, translate_function: Option<&TranslateFunction>) {
+        if pragmas::has_leading_pragma(self.comments, key.span().lo, pragmas::IGNORE) {
+            return;
+        }
+
+        match key {
+            // t(["errors:specific", "errors:generic"])
+            Expr::Array(array) => {
+                for elem in array.elems.iter().flatten() {
+                    self.push(&elem.expr, translate_function);
+                }
+            }
+            Expr::Lit(Lit::Str(_)) | Expr::Tpl(_) => self.keys.push(SinkKey {
+                key: key.clone(),
+                translate_function: translate_function.cloned(),
+                attribute: false,
+            }),
+            _ => (),
+        }
+    }
+
+    fn is_disabled(&self, span: Span) -> bool {
+        pragmas::has_leading_pragma(self.comments, span.lo, pragmas::DISABLE_NEXT_LINE)
+    }
+}
+
+impl Visit for SinkKeyCollector<'_> {
+    fn visit_module_item(&mut self, module_item: &ModuleItem) {
+        if !self.is_disabled(module_item.span()) {
+            module_item.visit_children_with(self);
+        }
+    }
+
+    fn visit_stmt(&mut self, stmt: &Stmt) {
+        if !self.is_disabled(stmt.span()) {
+            stmt.visit_children_with(self);
+        }
+    }
+
+    fn visit_prop_or_spread(&mut self, prop: &PropOrSpread) {
+        if !self.is_disabled(prop.span()) {
+            prop.visit_children_with(self);
+        }
+    }
+
+    fn visit_expr(&mut self, expr: &Expr) {
+        if !pragmas::has_leading_pragma(self.comments, expr.span().lo, pragmas::IGNORE) {
+            expr.visit_children_with(self);
+        }
+    }
+
+    fn visit_function(&mut self, function: &Function) {
+        self.scopes.push();
+        function.visit_children_with(self);
+        self.scopes.pop();
+    }
+
+    fn visit_arrow_expr(&mut self, arrow_expr: &ArrowExpr) {
+        self.scopes.push();
+        arrow_expr.visit_children_with(self);
+        self.scopes.pop();
+    }
+
+    fn visit_block_stmt(&mut self, block: &BlockStmt) {
+        self.scopes.push();
+        block.visit_children_with(self);
+        self.scopes.pop();
+    }
+
+    // function Checkout({ t }) {...} export default withTranslation("checkout")(Checkout);
+    fn visit_fn_decl(&mut self, fn_decl: &FnDecl) {
+        let entered = self.scopes.enter_component(&fn_decl.ident.sym);
+        fn_decl.visit_children_with(self);
+
+        if entered {
+            self.scopes.pop();
+        }
+    }
+
+    fn visit_class_decl(&mut self, class_decl: &ClassDecl) {
+        let entered = self.scopes.enter_component(&class_decl.ident.sym);
+        class_decl.visit_children_with(self);
+
+        if entered {
+            self.scopes.pop();
+        }
+    }
+
+    // const { t } = useTranslation("checkout"); const t = i18n.getFixedT(null, "checkout");
+    fn visit_var_declarator(&mut self, var_declarator: &VarDeclarator) {
+        // const Checkout = ({ t }) => {...};
+        let entered = match var_declarator.name.as_ident() {
+            Some(ident) => self.scopes.enter_component(&ident.sym),
+            None => false,
+        };
+
+        var_declarator.visit_children_with(self);
+
+        if entered {
+            self.scopes.pop();
+        }
+
+        self.scopes.bind_translate_functions(var_declarator);
+    }
+
+    // t("common:save"), i18n.t(`errors:${code}`)
+    fn visit_call_expr(&mut self, call_expr: &CallExpr) {
+        call_expr.visit_children_with(self);
+
+        let Some(callee) = callee_path(&call_expr.callee) else {
+            return;
+        };
+
+        let sink_arguments = self.scopes.sink_arguments(&self.config.sinks, &callee);
+
+        for (i, arg) in call_expr.args.iter().enumerate() {
+            if !sink_arguments.contains(&i) {
+                continue;
+            }
+
+            // only the key of a t function is relative to its namespace and key prefix
+            let translate_function = match i {
+                0 => self.scopes.translate_function(&callee).cloned(),
+                _ => None,
+            };
+
+            self.push(&arg.expr, translate_function.as_ref());
+        }
+    }
+
+    // <Trans i18nKey="common:welcome" />, <Trans i18nKey={`errors:${code}`} />
+    fn visit_jsx_opening_element(&mut self, jsx_opening_element: &JSXOpeningElement) {
+        jsx_opening_element.visit_children_with(self);
+
+        let component = jsx_element_name(&jsx_opening_element.name);
+
+        for attr in &jsx_opening_element.attrs {
+            let JSXAttrOrSpread::JSXAttr(attr) = attr else {
+                continue;
+            };
+
+            let JSXAttrName::Ident(name) = &attr.name else {
+                continue;
+            };
+
+            if !scope::is_jsx_sink(&self.config.sinks, &component, &name.sym) {
+                continue;
+            }
+
+            match &attr.value {
+                Some(JSXAttrValue::Str(str)) => self.keys.push(SinkKey {
+                    key: Expr::Lit(Lit::Str(str.clone())),
+                    translate_function: None,
+                    attribute: true,
+                }),
+                // <Trans i18nKey={/* translation-converter-ignore */ "common:welcome"} />
+                Some(JSXAttrValue::JSXExprContainer(jsx_expr_cont)) => {
+                    let after_brace = jsx_expr_cont.span.lo + BytePos(1);
+
+                    if pragmas::has_trailing_pragma(self.comments, after_brace, pragmas::IGNORE) {
+                        continue;
+                    }
+
+                    if let JSXExpr::Expr(expr) = &jsx_expr_cont.expr {
+                        self.push(expr, None);
+                    }
+                }
+                _ => (),
+            }
+        }
+    }
+}
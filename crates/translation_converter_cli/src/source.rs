@@ -0,0 +1,110 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+use swc_core::common::{comments::SingleThreadedComments, sync::Lrc, SourceFile, SourceMap};
+use swc_ecma_ast::Module;
+use swc_ecma_parser::{parse_file_as_module, EsSyntax, Syntax, TsSyntax};
+
+/// Extensions of the files looked at when a directory is given
+const EXTENSIONS: [&str; 4] = ["js", "jsx", "ts", "tsx"];
+
+/// A parsed source file
+pub struct Source {
+    pub file: Lrc<SourceFile>,
+    pub module: Module,
+    /// Needed by the converter for its pragmas, ex: // translation-converter-ignore
+    pub comments: SingleThreadedComments,
+}
+
+/// Expands the given paths into the source files they contain, sorted so outputs are stable
+/// node_modules and hidden directories are skipped
+pub fn files(paths: &[PathBuf]) -> Result<Vec<PathBuf>, String> {
+    let mut files = vec![];
+
+    for path in paths {
+        collect(path, &mut files)?;
+    }
+
+    files.sort();
+    Ok(files)
+}
+
+fn collect(path: &Path, files: &mut Vec<PathBuf>) -> Result<(), String> {
+    if !path.is_dir() {
+        files.push(path.to_path_buf());
+        return Ok(());
+    }
+
+    let entries =
+        fs::read_dir(path).map_err(|err| format!("unable to read {}: {err}", path.display()))?;
+
+    for entry in entries {
+        let path = entry
+            .map_err(|err| format!("unable to read {}: {err}", path.display()))?
+            .path();
+
+        let name = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .unwrap_or_default();
+
+        if name == "node_modules" || name.starts_with('.') {
+            continue;
+        }
+
+        let is_source = path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .is_some_and(|extension| EXTENSIONS.contains(&extension));
+
+        if path.is_dir() || is_source {
+            collect(&path, files)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Loads and parses a source file, the syntax is picked from its extension
+pub fn load(cm: &Lrc<SourceMap>, path: &Path) -> Result<Source, String> {
+    let file = cm
+        .load_file(path)
+        .map_err(|err| format!("unable to read {}: {err}", path.display()))?;
+
+    parse(file, syntax(path))
+}
+
+/// Parses a source file already added to the source map
+pub fn parse(file: Lrc<SourceFile>, syntax: Syntax) -> Result<Source, String> {
+    let comments = SingleThreadedComments::default();
+    let module = parse_file_as_module(
+        &file,
+        syntax,
+        Default::default(),
+        Some(&comments),
+        &mut vec![],
+    )
+    .map_err(|err| format!("unable to parse {}: {}", file.name, err.kind().msg()))?;
+
+    Ok(Source {
+        file,
+        module,
+        comments,
+    })
+}
+
+/// ex: Foo.tsx -> TypeScript with JSX, foo.ts -> TypeScript, foo.js -> JavaScript with JSX
+pub fn syntax(path: &Path) -> Syntax {
+    match path.extension().and_then(|extension| extension.to_str()) {
+        Some("ts") => Syntax::Typescript(TsSyntax::default()),
+        Some("tsx") => Syntax::Typescript(TsSyntax {
+            tsx: true,
+            ..Default::default()
+        }),
+        _ => Syntax::Es(EsSyntax {
+            jsx: true,
+            ..Default::default()
+        }),
+    }
+}
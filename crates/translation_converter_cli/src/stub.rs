@@ -0,0 +1,208 @@
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+use crate::{
+    declarations::{property, string},
+    resources::{translations, Translation},
+    ModuleFormat,
+};
+
+/// A module exporting l where every translation is the key it is converted into
+/// stands in for l where the plugin doesn't run, ex: jest and storybook
+///
+/// # Examples
+/// ```text
+/// // common.json: { "save": "Save", "items_one": "{{count}} item", "items_other": "{{count}} items" }
+/// let stub = stub(&resources, ModuleFormat::Esm);
+///
+/// // export const l = {
+/// //   common: {
+/// //     save: "common:save",
+/// //     items: "common:items",
+/// //   },
+/// // };
+/// ```
+pub fn stub(resources: &BTreeMap<String, Value>, format: ModuleFormat) -> String {
+    let l = Translation::Object(
+        resources
+            .iter()
+            .map(|(namespace, resource)| (namespace.clone(), translations(namespace, resource)))
+            .collect(),
+    );
+
+    let mut stub =
+        String::from("// Generated by translation-converter from the resources, do not edit\n\n");
+
+    stub += match format {
+        ModuleFormat::Esm => "export const l = ",
+        ModuleFormat::Commonjs => "exports.l = ",
+    };
+
+    object(&l, 0, &mut stub);
+    stub += ";\n";
+
+    stub
+}
+
+fn object(translation: &Translation, depth: usize, stub: &mut String) {
+    let translations = match translation {
+        Translation::Key(key) => {
+            *stub += &string(key);
+            return;
+        }
+        Translation::Object(translations) if translations.is_empty() => {
+            *stub += "{}";
+            return;
+        }
+        Translation::Object(translations) => translations,
+    };
+
+    let indent = "  ".repeat(depth);
+
+    *stub += "{\n";
+
+    for (name, translation) in translations {
+        *stub += &format!("{indent}  {}: ", property(name));
+        object(translation, depth + 1, stub);
+        *stub += ",\n";
+    }
+
+    *stub += &format!("{indent}}}");
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use std::collections::BTreeMap;
+    use swc_core::common::{sync::Lrc, FileName, SourceMap, GLOBALS};
+    use swc_ecma_ast::{
+        Decl, Expr, Lit, MemberExpr, ModuleDecl, ModuleItem, ObjectLit, Prop, PropName,
+    };
+    use swc_ecma_parser::parse_file_as_expr;
+    use translation_converter::translation_key;
+
+    use crate::{
+        source::{parse, syntax},
+        stub::stub,
+        ModuleFormat,
+    };
+
+    // The object exported as l by a stub
+    fn exported_l(stub: &str) -> ObjectLit {
+        let cm: Lrc<SourceMap> = Default::default();
+        let file = cm.new_source_file(
+            FileName::Custom("l.js".to_string()).into(),
+            stub.to_string(),
+        );
+        let source = parse(file, syntax("l.js".as_ref())).unwrap();
+
+        let Some(ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export))) =
+            source.module.body.first()
+        else {
+            panic!("the stub doesn't export l");
+        };
+        let Decl::Var(var_decl) = &export.decl else {
+            panic!("l is not a variable");
+        };
+
+        var_decl.decls[0].init.clone().unwrap().expect_object()
+    }
+
+    // The value of the stub at the path, ex: ["common", "form", "submit"] -> l.common.form.submit
+    fn lookup(object: &ObjectLit, path: &[&str]) -> Option<String> {
+        let (name, rest) = path.split_first()?;
+
+        let value = object.props.iter().find_map(|prop| {
+            let Prop::KeyValue(key_value) = &**prop.as_prop()? else {
+                return None;
+            };
+
+            let key = match &key_value.key {
+                PropName::Ident(ident) => ident.sym.to_string(),
+                PropName::Str(str) => str.value.to_string_lossy().to_string(),
+                _ => return None,
+            };
+
+            (key == *name).then_some(&key_value.value)
+        })?;
+
+        match &**value {
+            Expr::Object(object) => lookup(object, rest),
+            Expr::Lit(Lit::Str(str)) if rest.is_empty() => {
+                Some(str.value.to_string_lossy().to_string())
+            }
+            _ => None,
+        }
+    }
+
+    fn member(src: &str) -> MemberExpr {
+        let cm: Lrc<SourceMap> = Default::default();
+        let file = cm.new_source_file(FileName::Anon.into(), src.to_string());
+        let expr = parse_file_as_expr(
+            &file,
+            Default::default(),
+            Default::default(),
+            None,
+            &mut vec![],
+        );
+
+        expr.unwrap().expect_member()
+    }
+
+    #[test]
+    fn translations_are_the_keys_they_are_converted_into() {
+        let resources = BTreeMap::from([(
+            "common".to_string(),
+            json!({
+                "save": "Save",
+                "items_one": "{{count}} item",
+                "items_other": "{{count}} items",
+                "form": { "submit": "Submit", "foo-bar": "Foo" },
+                "legacy": {}
+            }),
+        )]);
+
+        assert_eq!(
+            stub(&resources, ModuleFormat::Commonjs),
+            r#"// Generated by translation-converter from the resources, do not edit
+
+exports.l = {
+  common: {
+    save: "common:save",
+    items: "common:items",
+    form: {
+      submit: "common:form.submit",
+      "foo-bar": "common:form.foo-bar",
+    },
+    legacy: {},
+  },
+};
+"#
+        );
+    }
+
+    #[test]
+    fn translations_of_the_stub_are_the_keys_the_plugin_converts_them_into() {
+        let resources = BTreeMap::from([(
+            "common".to_string(),
+            json!({ "form": { "submit": "Submit", "foo-bar": "Foo" } }),
+        )]);
+
+        GLOBALS.set(&Default::default(), || {
+            let l = exported_l(&stub(&resources, ModuleFormat::Esm));
+
+            // l.common.form.submit
+            assert_eq!(
+                lookup(&l, &["common", "form", "submit"]),
+                translation_key(&member("l.common.form.submit"))
+            );
+
+            // l.common.form[field] where field is "foo-bar"
+            let computed = translation_key(&member("l.common.form[field]"))
+                .map(|key| key.replace("${field}", "foo-bar"));
+
+            assert_eq!(lookup(&l, &["common", "form", "foo-bar"]), computed);
+            assert_eq!(computed.as_deref(), Some("common:form.foo-bar"));
+        });
+    }
+}
@@ -0,0 +1,317 @@
+use serde_json::Value;
+use std::collections::HashSet;
+use translation_converter::resources::plural_key;
+
+use crate::{
+    extract::{is_template, Reference},
+    resources::join,
+};
+
+/// Keys referenced across the codebase, keys with interpolated values are kept as the static part they start with
+/// ex: t(l.common.foo[bar].baz) -> common:foo.
+pub struct Usage {
+    keys: HashSet<String>,
+    prefixes: Vec<String>,
+}
+
+impl Usage {
+    pub fn new(references: &[Reference]) -> Usage {
+        let (templates, keys): (Vec<&Reference>, Vec<&Reference>) = references
+            .iter()
+            .partition(|reference| is_template(&reference.key));
+
+        Usage {
+            keys: keys.iter().map(|reference| reference.key.clone()).collect(),
+            prefixes: templates
+                .iter()
+                .filter_map(|reference| reference.key.split("${").next())
+                .map(str::to_string)
+                .collect(),
+        }
+    }
+
+    /// Whether a key of the resources is referenced, directly or through one of the objects containing it
+    /// ex: common:foo.bar is used by t(l.common.foo.bar), t(l.common.foo, { returnObjects: true }) and t(l.common.foo[bar])
+    /// plural forms are used by the key they belong to, ex: common:items_one is used by t(l.common.items)
+    /// computed members may stand for any path, every key under the static part of the reference is used
+    /// ex: common:foo.bar.qux is used by t(l.common.foo[bar].baz), every key is used by t(l[namespace].foo)
+    pub fn is_used(&self, key: &str) -> bool {
+        if self.prefixes.iter().any(|prefix| key.starts_with(prefix)) {
+            return true;
+        }
+
+        let keys = [Some(key), plural_key(key)];
+
+        keys.into_iter()
+            .flatten()
+            .flat_map(prefixes)
+            .any(|prefix| self.keys.contains(prefix))
+    }
+}
+
+/// The key along with the keys of the objects containing it
+/// ex: "common:foo.bar.baz" -> ["common:foo.bar.baz", "common:foo.bar", "common:foo"]
+fn prefixes(key: &str) -> impl Iterator<Item = &str> {
+    let namespace_end = key.find(':').map_or(0, |index| index + 1);
+
+    std::iter::once(key).chain(
+        key.char_indices()
+            .rev()
+            .filter(move |(index, char)| *char == '.' && *index > namespace_end)
+            .map(move |(index, _)| &key[..index]),
+    )
+}
+
+/// A key, or an object of keys, that is never referenced
+pub struct Unused {
+    pub key: String,
+    /// Number of keys within the object, None for a single key
+    pub keys: Option<usize>,
+}
+
+/// Collects the unused keys of the resource of a namespace
+/// objects whose keys are all unused are reported as a whole rather than key by key
+pub fn unused(namespace: &str, resource: &Value, usage: &Usage) -> Vec<Unused> {
+    let mut unused = vec![];
+
+    if let Value::Object(properties) = resource {
+        for (property, value) in properties {
+            collect(
+                namespace,
+                &join(namespace, None, property),
+                value,
+                usage,
+                &mut unused,
+            );
+        }
+    }
+
+    unused
+}
+
+// Returns whether every key of value is unused
+fn collect(
+    namespace: &str,
+    path: &str,
+    value: &Value,
+    usage: &Usage,
+    unused: &mut Vec<Unused>,
+) -> bool {
+    let Value::Object(properties) = value else {
+        let is_unused = !usage.is_used(path);
+
+        if is_unused {
+            unused.push(Unused {
+                key: path.to_string(),
+                keys: None,
+            });
+        }
+
+        return is_unused;
+    };
+
+    let mut children = vec![];
+    let mut all_unused = true;
+
+    for (property, value) in properties {
+        all_unused &= collect(
+            namespace,
+            &join(namespace, Some(path), property),
+            value,
+            usage,
+            &mut children,
+        );
+    }
+
+    if all_unused {
+        unused.push(Unused {
+            key: path.to_string(),
+            keys: Some(leaves(value)),
+        });
+    } else {
+        unused.extend(children);
+    }
+
+    all_unused
+}
+
+fn leaves(value: &Value) -> usize {
+    match value {
+        Value::Object(properties) => properties.values().map(leaves).sum(),
+        _ => 1,
+    }
+}
+
+/// Removes the unused keys of the resource of a namespace, objects left empty are removed as well
+pub fn prune(namespace: &str, resource: &mut Value, usage: &Usage) {
+    if let Value::Object(properties) = resource {
+        properties.retain(|property, value| {
+            retain(namespace, &join(namespace, None, property), value, usage)
+        });
+    }
+}
+
+// Returns whether value is kept
+fn retain(namespace: &str, path: &str, value: &mut Value, usage: &Usage) -> bool {
+    let Value::Object(properties) = value else {
+        return usage.is_used(path);
+    };
+
+    if properties.is_empty() {
+        return usage.is_used(path);
+    }
+
+    properties.retain(|property, value| {
+        retain(
+            namespace,
+            &join(namespace, Some(path), property),
+            value,
+            usage,
+        )
+    });
+
+    !properties.is_empty()
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use swc_core::common::{sync::Lrc, FileName, SourceMap, GLOBALS};
+    use translation_converter::config::Config;
+
+    use crate::{
+        extract::{references, Reference},
+        source::{parse, syntax},
+        unused::{prune, unused, Usage},
+    };
+
+    fn usage(keys: &[&str]) -> Usage {
+        let references: Vec<Reference> = keys
+            .iter()
+            .map(|key| Reference {
+                key: key.to_string(),
+                namespace: None,
+                file: "Foo.tsx".to_string(),
+                line: 1,
+            })
+            .collect();
+
+        Usage::new(&references)
+    }
+
+    #[test]
+    fn keys_are_used_by_references_to_them_or_their_objects() {
+        let usage = usage(&[
+            "common:save",
+            "common:form",
+            "common:items",
+            "common:errors.${code}",
+        ]);
+
+        assert!(usage.is_used("common:save"));
+        assert!(usage.is_used("common:form.submit"));
+        assert!(usage.is_used("common:items_one"));
+        assert!(usage.is_used("common:errors.declined.title"));
+        assert!(!usage.is_used("common:cancel"));
+        assert!(!usage.is_used("checkout:save"));
+    }
+
+    #[test]
+    fn computed_members_use_every_key_under_their_static_part() {
+        let steps = usage(&["checkout:steps.${step}.title"]);
+
+        assert!(steps.is_used("checkout:steps.payment.title"));
+        assert!(steps.is_used("checkout:steps.payment.summary.total"));
+        assert!(!steps.is_used("checkout:total"));
+
+        let namespaces = usage(&["${namespace}:foo"]);
+
+        assert!(namespaces.is_used("common:save"));
+        assert!(namespaces.is_used("checkout:total"));
+    }
+
+    #[test]
+    fn reports_unused_objects_as_a_whole() {
+        let resource = json!({
+            "save": "Save",
+            "cancel": "Cancel",
+            "legacy": { "foo": "Foo", "bar": { "baz": "Baz" } },
+            "form": { "submit": "Submit", "reset": "Reset" }
+        });
+
+        let unused: Vec<(String, Option<usize>)> = unused(
+            "common",
+            &resource,
+            &usage(&["common:save", "common:form.submit"]),
+        )
+        .into_iter()
+        .map(|unused| (unused.key, unused.keys))
+        .collect();
+
+        assert_eq!(
+            unused,
+            vec![
+                ("common:cancel".to_string(), None),
+                ("common:legacy".to_string(), Some(2)),
+                ("common:form.reset".to_string(), None)
+            ]
+        );
+    }
+
+    #[test]
+    fn prunes_unused_keys_and_empty_objects() {
+        let mut resource = json!({
+            "save": "Save",
+            "legacy": { "foo": "Foo" },
+            "form": { "submit": "Submit", "reset": "Reset" }
+        });
+
+        prune(
+            "common",
+            &mut resource,
+            &usage(&["common:save", "common:form.submit"]),
+        );
+
+        assert_eq!(
+            resource,
+            json!({ "save": "Save", "form": { "submit": "Submit" } })
+        );
+    }
+
+    #[test]
+    fn prune_keeps_keys_passed_to_sinks_as_strings() {
+        let references = GLOBALS.set(&Default::default(), || {
+            let cm: Lrc<SourceMap> = Default::default();
+            let file = cm.new_source_file(
+                FileName::Custom("Foo.tsx".to_string()).into(),
+                r#"const { t } = useTranslation("common", { keyPrefix: "form" });
+i18n.t("common:save");
+t("submit");
+<Trans i18nKey={`common:errors.${code}`} />;
+"#
+                .to_string(),
+            );
+            let source = parse(file, syntax("Foo.tsx".as_ref())).unwrap();
+
+            references(&cm, &source, &Config::parse("{}", None))
+        });
+
+        let mut resource = json!({
+            "save": "Save",
+            "cancel": "Cancel",
+            "form": { "submit": "Submit", "reset": "Reset" },
+            "errors": { "declined": "Declined" }
+        });
+
+        prune("common", &mut resource, &Usage::new(&references));
+
+        assert_eq!(
+            resource,
+            json!({
+                "save": "Save",
+                "form": { "submit": "Submit" },
+                "errors": { "declined": "Declined" }
+            })
+        );
+    }
+}
@@ -1,148 +1,49 @@
-use builders::lib::jsx_expr;
+use std::path::Path;
 use swc_core::{
-    common::Spanned,
     ecma::transforms::testing::test,
-    plugin::{plugin_transform, proxies::TransformPluginProgramMetadata},
+    plugin::{
+        metadata::TransformPluginMetadataContextKind, plugin_transform,
+        proxies::TransformPluginProgramMetadata,
+    },
 };
-use swc_ecma_ast::{
-    CallExpr, CondExpr, ExprOrSpread, JSXExpr, JSXExprContainer, KeyValueProp, Program, ReturnStmt,
+use swc_ecma_ast::Program;
+use swc_ecma_visit::{visit_mut_pass, VisitMut};
+use translation_converter::{
+    config::{relative_filename, Config},
+    TranslationConverter,
 };
-use swc_ecma_visit::{visit_mut_pass, VisitMut, VisitMutWith};
 
-mod builders {
-    pub mod lib;
-    pub mod serializers;
-    pub mod utils;
-}
-
-struct TranslationConverterVisitor;
-
-impl VisitMut for TranslationConverterVisitor {
-    // t(l.common.foo...);
-    fn visit_mut_call_expr(&mut self, call_expr: &mut CallExpr) {
-        // required to ensure that other visit_mut fn are called for children
-        call_expr.visit_mut_children_with(self);
-
-        // if arguments are empty, skip, nothing to do
-        // This would only happen if you used t like so t();
-        // Not sure why you'd do that but hey
-        if call_expr.args.is_empty() {
-            return;
-        }
-
-        // We must loop through all args of the call_expr for cases like so:
-        // ex: mobileHeaderContent(faChevronDown, l.common.ShowOrderSummary)
-        for (i, arg) in call_expr.args.clone().iter().enumerate() {
-            // t(l.common.foobar);
-            if arg.expr.is_member() {
-                let member_expr = arg.expr.as_member().unwrap();
-                let box_expr = builders::lib::box_expr(member_expr, arg.span());
-
-                if box_expr.is_some() {
-                    call_expr.args[i] = ExprOrSpread {
-                        spread: None,
-                        expr: box_expr.unwrap(),
-                    }
-                }
-            }
-        }
-    }
-
-    // for cases where the translation is nested inside a conditional statement somewhere
-    // isFoo ? l.common.foo : l.common.bar;
-    fn visit_mut_cond_expr(&mut self, cond_expr: &mut CondExpr) {
-        // required to ensure that other visit_mut fn are called for children
-        cond_expr.visit_mut_children_with(self);
-
-        // cons here being the first result in our ternary if truthy
-        // from the above example comment that would be l.common.foo
-        if cond_expr.cons.is_member() {
-            let member_expr = cond_expr.cons.as_member().unwrap();
-            let box_expr = builders::lib::box_expr(member_expr, cond_expr.cons.span());
-
-            if box_expr.is_some() {
-                cond_expr.cons = box_expr.unwrap();
-            }
-        }
-
-        // alt here being the second result in our ternary if falsy
-        // from the above example comment that would be l.common.bar
-        if cond_expr.alt.is_member() {
-            let member_expr = cond_expr.alt.as_member().unwrap();
-            let box_expr = builders::lib::box_expr(member_expr, cond_expr.alt.span());
-
-            if box_expr.is_some() {
-                cond_expr.alt = box_expr.unwrap();
-            }
-        }
-    }
-
-    // for cases where the translation is returned as part of a function and not nested inside of a t call
-    // const func = () => l.common.foobar;
-    fn visit_mut_return_stmt(&mut self, return_stmt: &mut ReturnStmt) {
-        // required to ensure that other visit_mut fn are called for children
-        return_stmt.visit_mut_children_with(self);
-
-        // If there's no arguments to our return statement, we don't need to do anything
-        if return_stmt.arg.is_none() {
-            return;
-        }
-
-        // we only care about the first argument in a t expression
-        // second argument could be variables: t(l.common.foobar, { count });
-        // when second argument is a translation, that is handled by visit_mut_key_value_prop
-        let arg = return_stmt.arg.clone().unwrap();
-
-        if arg.is_member() {
-            let member_expr = arg.as_member().unwrap();
-            let box_expr = builders::lib::box_expr(member_expr, return_stmt.arg.span());
-
-            if box_expr.is_some() {
-                return_stmt.arg = box_expr;
-            }
-        }
-    }
-
-    // for cases where translation is used as a variable
-    // t(l.common.foobar, { label: l.common.label });
-    fn visit_mut_key_value_prop(&mut self, key_value_prop: &mut KeyValueProp) {
-        // required to ensure that other visit_mut fn are called for children
-        key_value_prop.visit_mut_children_with(self);
-
-        if key_value_prop.value.is_member() {
-            let member_expr = key_value_prop.value.as_member().unwrap();
-            let box_expr = builders::lib::box_expr(member_expr, key_value_prop.value.span());
-
-            if box_expr.is_some() {
-                key_value_prop.value = box_expr.unwrap();
-            }
+#[plugin_transform]
+pub fn process_transform(program: Program, metadata: TransformPluginProgramMetadata) -> Program {
+    // Options of the env section matching the SWC env are merged over the base options
+    let mut config = Config::parse(
+        &metadata
+            .get_transform_plugin_config()
+            .unwrap_or_else(|| "{}".to_string()),
+        metadata
+            .get_context(&TransformPluginMetadataContextKind::Env)
+            .as_deref(),
+    );
+
+    // Files that are not included or that are excluded are returned untouched
+    if let Some(filename) = metadata.get_context(&TransformPluginMetadataContextKind::Filename) {
+        let cwd = metadata.get_context(&TransformPluginMetadataContextKind::Cwd);
+
+        if !config.should_transform(relative_filename(&filename, cwd.as_deref())) {
+            return program;
         }
     }
 
-    fn visit_mut_jsx_expr_container(&mut self, jsx_expr_cont: &mut JSXExprContainer) {
-        // required to ensure that other visit_mut fn are called for children
-        jsx_expr_cont.visit_mut_children_with(self);
-
-        match jsx_expr_cont.expr.clone() {
-            JSXExpr::JSXEmptyExpr(_) => return,
-            JSXExpr::Expr(expr) => {
-                if expr.is_member() {
-                    let member_expr = expr.as_member().unwrap();
-                    let box_expr = builders::lib::box_expr(member_expr, expr.span());
-
-                    if box_expr.is_some() {
-                        jsx_expr_cont.expr = jsx_expr(member_expr, jsx_expr_cont.span()).unwrap();
-                    }
-                }
-            }
+    // The working directory is mounted at /cwd in the plugin
+    if let Some(resources) = &mut config.resources {
+        if Path::new(resources).is_relative() {
+            *resources = format!("/cwd/{resources}");
         }
     }
-}
 
-#[plugin_transform]
-pub fn process_transform(program: Program, _metadata: TransformPluginProgramMetadata) -> Program {
     let mut program = program;
-    visit_mut_pass(TranslationConverterVisitor).visit_mut_program(&mut program);
+    visit_mut_pass(TranslationConverter::new(metadata.comments, config))
+        .visit_mut_program(&mut program);
     program
 }
 
@@ -151,32 +52,41 @@ use swc_ecma_parser::Syntax;
 
 #[cfg(test)]
 fn config() -> Syntax {
-    return swc_ecma_parser::Syntax::Typescript(swc_ecma_parser::TsSyntax {
+    swc_ecma_parser::Syntax::Typescript(swc_ecma_parser::TsSyntax {
         tsx: true,
         decorators: false,
         dts: true,
         no_early_errors: true,
         disallow_ambiguous_jsx_like: true,
-    });
+    })
 }
 
 test!(
     config(),
-    |_| visit_mut_pass(TranslationConverterVisitor),
+    |t| visit_mut_pass(TranslationConverter::new(
+        t.comments.clone(),
+        Config::default()
+    )),
     converts_l_member_expressions_inside_of_t_functions,
     r#"t(l.common.fooBar);"#
 );
 
 test!(
     config(),
-    |_| visit_mut_pass(TranslationConverterVisitor),
+    |t| visit_mut_pass(TranslationConverter::new(
+        t.comments.clone(),
+        Config::default()
+    )),
     converts_l_member_expressions_inside_of_t_func_with_variables,
     r#"t(l.common.fooBar, { userName });"#
 );
 
 test!(
     config(),
-    |_| visit_mut_pass(TranslationConverterVisitor),
+    |t| visit_mut_pass(TranslationConverter::new(
+        t.comments.clone(),
+        Config::default()
+    )),
     converts_l_to_template_literal_member_expressions,
     r#"
     const bar = 'cat';
@@ -186,35 +96,50 @@ test!(
 
 test!(
     config(),
-    |_| visit_mut_pass(TranslationConverterVisitor),
+    |t| visit_mut_pass(TranslationConverter::new(
+        t.comments.clone(),
+        Config::default()
+    )),
     converts_l_template_literal_member_expressions_with_variable_namespace,
     r#"t(l[common].foo[bar]);"#
 );
 
 test!(
     config(),
-    |_| visit_mut_pass(TranslationConverterVisitor),
+    |t| visit_mut_pass(TranslationConverter::new(
+        t.comments.clone(),
+        Config::default()
+    )),
     converts_l_template_literal_member_expressions_with_trailing_quasis,
     r#"t(l[common].foo1[bar1].foo2[bar2].foo3);"#
 );
 
 test!(
     config(),
-    |_| visit_mut_pass(TranslationConverterVisitor),
+    |t| visit_mut_pass(TranslationConverter::new(
+        t.comments.clone(),
+        Config::default()
+    )),
     converts_l_template_literal_member_expressions_with_expression_in_middle,
     r#"t(l.common.foo1[bar1].foo3);"#
 );
 
 test!(
     config(),
-    |_| visit_mut_pass(TranslationConverterVisitor),
+    |t| visit_mut_pass(TranslationConverter::new(
+        t.comments.clone(),
+        Config::default()
+    )),
     converts_l_that_is_part_of_ternary,
     r#"t(something ? l.user.foo : l.user.bar);"#
 );
 
 test!(
     config(),
-    |_| visit_mut_pass(TranslationConverterVisitor),
+    |t| visit_mut_pass(TranslationConverter::new(
+        t.comments.clone(),
+        Config::default()
+    )),
     converts_l_that_is_outside_of_t_inside_a_function,
     r#"
     const testFunc = () => {
@@ -225,7 +150,10 @@ test!(
 
 test!(
     config(),
-    |_| visit_mut_pass(TranslationConverterVisitor),
+    |t| visit_mut_pass(TranslationConverter::new(
+        t.comments.clone(),
+        Config::default()
+    )),
     converts_cond_expr_with_l_in_functions,
     r#"
     const testFunc = () => {
@@ -236,42 +164,60 @@ test!(
 
 test!(
     config(),
-    |_| visit_mut_pass(TranslationConverterVisitor),
+    |t| visit_mut_pass(TranslationConverter::new(
+        t.comments.clone(),
+        Config::default()
+    )),
     converts_l_with_many_nested_namesapces,
     r#"t(l.clerk.one.two.three.four);"#
 );
 
 test!(
     config(),
-    |_| visit_mut_pass(TranslationConverterVisitor),
+    |t| visit_mut_pass(TranslationConverter::new(
+        t.comments.clone(),
+        Config::default()
+    )),
     converts_nested_l_member_expression,
     r#"t(l.userName.bla, { label: l.userName.label });"#
 );
 
 test!(
     config(),
-    |_| visit_mut_pass(TranslationConverterVisitor),
+    |t| visit_mut_pass(TranslationConverter::new(
+        t.comments.clone(),
+        Config::default()
+    )),
     does_not_convert_member_expressions_that_do_not_start_with_l,
     r#"t(b.userName.bla);"#
 );
 
 test!(
     config(),
-    |_| visit_mut_pass(TranslationConverterVisitor),
+    |t| visit_mut_pass(TranslationConverter::new(
+        t.comments.clone(),
+        Config::default()
+    )),
     converts_nested_t_functions,
     r#"<Component>{t(l.common.foo1, { label: t(l.common.foo2[bar]) })}</Component>"#
 );
 
 test!(
     config(),
-    |_| visit_mut_pass(TranslationConverterVisitor),
+    |t| visit_mut_pass(TranslationConverter::new(
+        t.comments.clone(),
+        Config::default()
+    )),
     converts_trans_i18n_key,
     r#"<Trans i18nKey={l.common.foobar}>hello world</Trans>"#
 );
 
 test!(
     config(),
-    |_| visit_mut_pass(TranslationConverterVisitor),
+    |t| visit_mut_pass(TranslationConverter::new(
+        t.comments.clone(),
+        Config::default()
+    )),
     converts_trans_nested_in_call_expr_in_jsx_expr,
     r#"
     <Collapsible
@@ -283,7 +229,10 @@ test!(
 
 test!(
     config(),
-    |_| visit_mut_pass(TranslationConverterVisitor),
+    |t| visit_mut_pass(TranslationConverter::new(
+        t.comments.clone(),
+        Config::default()
+    )),
     converts_l_nested_in_template_literals,
     r#"
     const variable = `${obj.property} ${t(l.common.foo1, {
@@ -294,17 +243,606 @@ test!(
 
 test!(
     config(),
-    |_| visit_mut_pass(TranslationConverterVisitor),
+    |t| visit_mut_pass(TranslationConverter::new(
+        t.comments.clone(),
+        Config::default()
+    )),
     converts_l_template_literal_member_expressions_with_nested_conditional,
     r#"t(l.common.foo[bar ? "bar" : "baz"]);"#
 );
 
 test!(
     config(),
-    |_| visit_mut_pass(TranslationConverterVisitor),
+    |t| visit_mut_pass(TranslationConverter::new(
+        t.comments.clone(),
+        Config::default()
+    )),
     converts_l_template_literal_member_expressions_with_computed_member_expressions,
     r#"
     const bar = {namespace: 'common', cat: 'kitty'};
     t(l[bar.namespace].foo[bar.cat]);
     "#
 );
+
+test!(
+    config(),
+    |t| visit_mut_pass(TranslationConverter::new(
+        t.comments.clone(),
+        Config::default()
+    )),
+    does_not_convert_files_with_disable_pragma,
+    r#"
+    // translation-converter-disable
+    t(l.common.foo1);
+    const testFunc = () => {
+      return l.common.foo2;
+    }
+    "#
+);
+
+test!(
+    config(),
+    |t| visit_mut_pass(TranslationConverter::new(
+        t.comments.clone(),
+        Config::default()
+    )),
+    does_not_convert_statements_with_disable_next_line_pragma,
+    r#"
+    const testFunc = () => {
+      // translation-converter-disable-next-line
+      const allStrings = [l.common.foo1, t(l.common.foo2)];
+      return t(l.common.foo3);
+    }
+    "#
+);
+
+test!(
+    config(),
+    |t| visit_mut_pass(TranslationConverter::new(
+        t.comments.clone(),
+        Config::default()
+    )),
+    does_not_convert_properties_with_disable_next_line_pragma,
+    r#"
+    t(l.common.foo1, {
+      // translation-converter-disable-next-line
+      label: l.common.foo2,
+      title: l.common.foo3,
+    });
+    "#
+);
+
+test!(
+    config(),
+    |t| visit_mut_pass(TranslationConverter::new(
+        t.comments.clone(),
+        Config::default()
+    )),
+    does_not_convert_expressions_with_ignore_pragma,
+    r#"
+    t(/* translation-converter-ignore */ l.common.foo1, { label: l.common.foo2 });
+    t(/* translation-converter-ignore */ isFoo ? l.common.foo3 : l.common.foo4);
+    <Trans i18nKey={/* translation-converter-ignore */ l.common.foo5}>hello world</Trans>;
+    "#
+);
+
+test!(
+    config(),
+    |t| visit_mut_pass(TranslationConverter::new(
+        t.comments.clone(),
+        Config::parse(r#"{ "annotate": true }"#, None)
+    )),
+    annotates_converted_translations,
+    r#"
+    t(l.common.foo1, { label: l.common.foo2 });
+    t(l.common.foo3[bar]);
+    <Trans i18nKey={l.common.foo4}>hello world</Trans>;
+    "#
+);
+
+test!(
+    config(),
+    |t| visit_mut_pass(TranslationConverter::new(
+        t.comments.clone(),
+        Config::default()
+    )),
+    converts_l_rendered_as_jsx_children_into_translate_calls,
+    r#"
+    const Checkout = () => {
+      const { t } = useTranslation();
+
+      return (
+        <Component title={l.common.foo1}>
+          {l.common.foo2}
+          <p>{l.common.foo3[bar]}</p>
+          {isFoo ? l.common.foo4 : l.common.foo5}
+          {isBar && (l.common.foo6 ?? l.common.foo7)}
+        </Component>
+      );
+    }
+    "#
+);
+
+test!(
+    config(),
+    |t| visit_mut_pass(TranslationConverter::new(
+        t.comments.clone(),
+        Config::parse(r#"{ "translate": { "callee": "i18n.t" } }"#, None)
+    )),
+    converts_l_rendered_as_jsx_children_with_configured_translate_callee,
+    r#"
+    import i18n from "i18next";
+
+    <p>{l.common.foo}</p>;
+    "#
+);
+
+test!(
+    config(),
+    |t| visit_mut_pass(TranslationConverter::new(
+        t.comments.clone(),
+        Config::parse(
+            r#"{
+                "translate": { "callee": "i18n.t", "import": { "source": "i18next" } },
+                "autoWrap": { "attributes": ["label", "title"], "positions": ["return", "property"] }
+            }"#,
+            None
+        )
+    )),
+    wraps_displayed_translations_and_injects_translate_import,
+    r#"
+    import React from "react";
+
+    const getLabel = () => {
+      return l.common.foo1;
+    }
+
+    const Component = () => (
+      <Trans i18nKey={l.common.foo2} title={l.common.foo3}>
+        <Button label={l.common.foo4[bar]} options={{ placeholder: l.common.foo5 }} />
+        {isFoo ? l.common.foo6 : l.common.foo7}
+      </Trans>
+    );
+    "#
+);
+
+test!(
+    config(),
+    |t| visit_mut_pass(TranslationConverter::new(
+        t.comments.clone(),
+        Config::parse(
+            r#"{
+                "translate": { "callee": "translate", "import": { "source": "i18next", "name": "t" } },
+                "autoWrap": { "positions": ["conditional"] }
+            }"#,
+            None
+        )
+    )),
+    wraps_displayed_translations_with_named_translate_import,
+    r#"
+    import { translate } from "./i18n";
+
+    const label = isFoo ? l.common.foo1 : l.common.foo2;
+    "#
+);
+
+test!(
+    config(),
+    |t| visit_mut_pass(TranslationConverter::new(
+        t.comments.clone(),
+        Config::parse(
+            r#"{
+                "translate": { "callee": "i18n.t", "import": { "source": "i18next" } },
+                "autoWrap": { "positions": ["conditional"] }
+            }"#,
+            None
+        )
+    )),
+    does_not_wrap_conditional_translations_passed_to_sinks,
+    r#"
+    const label = isFoo ? l.common.foo1 : l.common.foo2;
+
+    t(isFoo ? l.common.foo3 : l.common.foo4);
+    <Trans i18nKey={isFoo ? l.common.foo5 : l.common.foo6} />;
+    "#
+);
+
+test!(
+    config(),
+    |t| visit_mut_pass(TranslationConverter::new(
+        t.comments.clone(),
+        Config::parse(
+            r#"{
+                "translate": { "callee": "i18n.t", "import": { "source": "i18next" } },
+                "autoWrap": { "positions": ["property"] }
+            }"#,
+            None
+        )
+    )),
+    does_not_wrap_properties_of_sink_options,
+    r#"
+    const options = { label: l.common.foo1 };
+
+    t(l.common.foo2, { label: l.common.foo3 });
+    t(l.common.foo4, { label: format({ value: l.common.foo5 }) });
+    "#
+);
+
+test!(
+    config(),
+    |t| visit_mut_pass(TranslationConverter::new(
+        t.comments.clone(),
+        Config::parse(r#"{ "mode": "targeted" }"#, None)
+    )),
+    converts_l_passed_to_sinks_in_targeted_mode,
+    r#"
+    const Component = () => {
+      const { t: translate } = useTranslation();
+
+      return (
+        <Trans i18nKey={l.common.foo1}>
+          {t(l.common.foo2, { label: i18n.t(l.common.foo3) })}
+          {translate(isFoo ? l.common.foo4 : l.common.foo5[bar])}
+        </Trans>
+      );
+    }
+    "#
+);
+
+test!(
+    config(),
+    |t| visit_mut_pass(TranslationConverter::new(
+        t.comments.clone(),
+        Config::parse(
+            r#"{
+                "mode": "targeted",
+                "sinks": {
+                    "callees": [{ "callee": "mobileHeaderContent", "arguments": [1] }],
+                    "jsx": [{ "component": "Collapsible", "attribute": "label" }]
+                }
+            }"#,
+            None
+        )
+    )),
+    converts_l_passed_to_configured_sinks_in_targeted_mode,
+    r#"
+    <Collapsible
+      label={l.common.Summary}
+      trigger={mobileHeaderContent(faChevronDown, l.common.ShowOrderSummary)}
+    />
+    "#
+);
+
+test!(
+    config(),
+    |t| visit_mut_pass(TranslationConverter::new(
+        t.comments.clone(),
+        Config::default()
+    )),
+    shortens_keys_in_namespaces_loaded_by_use_translation,
+    r#"
+    const Checkout = () => {
+      const { t } = useTranslation(["checkout", "common"]);
+      const translate = i18n.getFixedT(null, "orders");
+
+      return (
+        <p>
+          {t(l.checkout.total)}
+          {t(l.checkout.items[count])}
+          {t(l.common.save)}
+          {t(l.orders.status)}
+          {translate(l.orders.status)}
+        </p>
+      );
+    }
+
+    const Other = () => t(l.checkout.total);
+    "#
+);
+
+test!(
+    config(),
+    |t| visit_mut_pass(TranslationConverter::new(
+        t.comments.clone(),
+        Config::default()
+    )),
+    shortens_keys_in_namespaces_given_to_with_translation,
+    r#"
+    function Summary({ t }) {
+      return <p>{t(l.checkout.total)}</p>;
+    }
+
+    class Cart extends Component {
+      render() {
+        return <p>{this.props.t(l.cart.empty)}{this.props.t(l.common.save)}</p>;
+      }
+    }
+
+    export const SummaryWithTranslation = withTranslation("checkout")(Summary);
+    export default withTranslation(["cart", "common"])(Cart);
+    "#
+);
+
+test!(
+    config(),
+    |t| visit_mut_pass(TranslationConverter::new(
+        t.comments.clone(),
+        Config::default()
+    )),
+    strips_key_prefix_given_to_use_translation,
+    r#"
+    const Summary = () => {
+      const { t } = useTranslation(["checkout", "common"], { keyPrefix: "summary" });
+      const translate = i18n.getFixedT(null, "orders", "status");
+
+      return (
+        <p>
+          {t(l.checkout.summary.total)}
+          {t(l.checkout.summary.items[count])}
+          {t(l.common.summary.save)}
+          {t(l.checkout.header.title)}
+          {translate(l.orders.status.shipped)}
+        </p>
+      );
+    }
+    "#
+);
+
+test!(
+    config(),
+    |t| visit_mut_pass(TranslationConverter::new(
+        t.comments.clone(),
+        Config::parse(r#"{ "namespaceManifest": {} }"#, None)
+    )),
+    exports_namespaces_used_by_the_module,
+    r#"
+    import { useTranslation } from "react-i18next";
+
+    const Checkout = () => {
+      const { t } = useTranslation("checkout");
+
+      return <p>{t(l.common.foo1)}{t(l.checkout.foo2)}{t(l.common.foo3[bar])}{t(l[namespace].foo4)}</p>;
+    }
+    "#
+);
+
+test!(
+    config(),
+    |t| visit_mut_pass(TranslationConverter::new(
+        t.comments.clone(),
+        Config::parse(
+            r#"{ "namespaceManifest": { "name": "namespaces", "computed": "wildcard" } }"#,
+            None
+        )
+    )),
+    exports_computed_namespaces_as_wildcard,
+    r#"
+    import { t } from "i18next";
+
+    const label = t(l[namespace].foo1);
+    "#
+);
+
+test!(
+    config(),
+    |t| visit_mut_pass(TranslationConverter::new(
+        t.comments.clone(),
+        Config::parse(r#"{ "preloadNamespaces": true }"#, None)
+    )),
+    fills_use_translation_with_namespaces_used_by_the_function,
+    r#"
+    function Checkout({ items }) {
+      const { t } = useTranslation();
+
+      const Item = () => {
+        const { t } = useTranslation();
+        return <p>{t(l.cart.foo1)}</p>;
+      };
+
+      return (
+        <div>
+          {t(l.common.foo2)}
+          {items.map(function (item) {
+            return t(l.checkout.foo3[item]);
+          })}
+          <Item />
+        </div>
+      );
+    }
+
+    function Header() {
+      const { t } = useTranslation("header");
+      return <h1>{t(l.header.foo4)}</h1>;
+    }
+    "#
+);
+
+test!(
+    config(),
+    |t| visit_mut_pass(TranslationConverter::new(
+        t.comments.clone(),
+        Config::parse(
+            r#"{
+                "remap": { "common.form": "forms", "common.form.legacy": "forms.fields", "shared": "common" },
+                "warnRemapped": true
+            }"#,
+            None
+        )
+    )),
+    converts_remapped_translations_to_their_new_path,
+    r#"
+    t(l.common.form.submit);
+    t(l.common.form.legacy.name[field]);
+    t(l.common.format);
+    t(l.shared.foo[bar]);
+    t(l.common[form].submit);
+    "#
+);
+
+test!(
+    config(),
+    |t| visit_mut_pass(TranslationConverter::new(
+        t.comments.clone(),
+        Config::parse(
+            r#"{
+                "remap": { "shared": "common" },
+                "deprecatedKeys": { "common:save": "common:submit", "common:cancel": "forms:cancel" }
+            }"#,
+            None
+        )
+    )),
+    replaces_deprecated_keys_with_their_new_key,
+    r#"
+    t(l.common.save);
+    t(l.shared.cancel);
+    t(l.common.save[bar]);
+    t(l.common.saved);
+    "#
+);
+
+test!(
+    config(),
+    |t| visit_mut_pass(TranslationConverter::new(
+        t.comments.clone(),
+        Config::parse(
+            r#"{
+                "roots": { "commonL": "common" },
+                "rootImports": { "locales/en/checkout.json": "checkout" }
+            }"#,
+            None
+        )
+    )),
+    converts_roots_mapped_to_fixed_namespaces,
+    r#"
+    import checkoutL from "locales/en/checkout.json";
+    import * as ordersL from "locales/en/orders.json";
+
+    t(checkoutL.summary.total);
+    t(checkoutL.items[count]);
+    t(commonL.save);
+    t(ordersL.status);
+    t(l.common.cancel);
+    "#
+);
+
+test!(
+    config(),
+    |t| visit_mut_pass(TranslationConverter::new(
+        t.comments.clone(),
+        Config::default()
+    )),
+    converts_aliased_and_destructured_translations,
+    r#"
+    import { l } from "./locales";
+
+    const { common, checkout: c } = l;
+
+    function Summary() {
+      const ns = l.orders;
+      const { status } = ns;
+      const labels = l.cart;
+
+      return (
+        <p>
+          {t(ns.foo1)}
+          {t(status.foo2[bar])}
+          {t(common.foo3)}
+          {t(c.summary.foo4)}
+          {Object.keys(labels)}
+        </p>
+      );
+    }
+
+    export const Header = () => <h1>{t(common.foo5)}</h1>;
+    "#
+);
+
+test!(
+    config(),
+    |t| visit_mut_pass(TranslationConverter::new(
+        t.comments.clone(),
+        Config::default()
+    )),
+    leaves_bindings_shadowing_aliases_untouched,
+    r#"
+    import { l } from "./locales";
+
+    const { common, checkout } = l;
+
+    function format(common) {
+      return common.foo1;
+    }
+
+    function Summary() {
+      t(common.foo2);
+
+      if (isFoo) {
+        const checkout = getCheckout();
+        t(checkout.foo3);
+      }
+
+      try {
+        t(checkout.foo4);
+      } catch (common) {
+        report(common.foo5);
+      }
+    }
+    "#
+);
+
+test!(
+    config(),
+    |t| visit_mut_pass(TranslationConverter::new(
+        t.comments.clone(),
+        Config::parse(
+            r#"{ "resources": "crates/translation_converter/tests/resources/en" }"#,
+            None
+        )
+    )),
+    converts_arrays_of_fallback_keys,
+    r#"
+    const Payment = () => {
+      const { t } = useTranslation("errors");
+
+      return (
+        <div>
+          {t([l.errors.payment[code], l.errors.generic])}
+          {t([l.errors.payment.declined, l.errors.missing])}
+          <Trans i18nKey={[l.common.foo1, l.common.save]} />
+          <Select options={[l.common.foo2]} />
+        </div>
+      );
+    }
+    "#
+);
+
+test!(
+    config(),
+    |t| visit_mut_pass(TranslationConverter::new(
+        t.comments.clone(),
+        Config::parse(
+            r#"{ "mode": "lint", "translate": { "callee": "i18n.t", "import": { "source": "i18next" } } }"#,
+            None
+        )
+    )),
+    leaves_the_program_untouched_in_lint_mode,
+    r#"
+    import { useTranslation } from "react-i18next";
+
+    const Checkout = () => {
+      const { t } = useTranslation("checkout", { keyPrefix: "summary" });
+      const common = l.common;
+
+      return (
+        <div>
+          <p>{l.common.foo1}</p>
+          {t("total")}
+          {t(`errors:${code}`)}
+          {t(common.foo2)}
+          {t(/* translation-converter-ignore */ "common:legacy")}
+          <Trans i18nKey="common:welcome" />
+        </div>
+      );
+    }
+    "#
+);
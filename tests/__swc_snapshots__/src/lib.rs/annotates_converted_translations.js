@@ -0,0 +1,5 @@
+t("common:foo1" /* l.common.foo1 */ , {
+    label: "common:foo2" /* l.common.foo2 */ 
+});
+t(`common:foo3.${bar}` /* l.common.foo3[bar] */ );
+<Trans i18nKey={"common:foo4" /* l.common.foo4 */ }>hello world</Trans>;
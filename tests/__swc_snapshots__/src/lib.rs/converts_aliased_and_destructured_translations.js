@@ -0,0 +1,12 @@
+import { l } from "./locales";
+function Summary() {
+    const labels = l.cart;
+    return <p>
+          {t("orders:foo1")}
+          {t(`orders:status.foo2.${bar}`)}
+          {t("common:foo3")}
+          {t("checkout:summary.foo4")}
+          {Object.keys(labels)}
+        </p>;
+}
+export const Header = ()=><h1>{t("common:foo5")}</h1>;
@@ -0,0 +1,20 @@
+const Payment = ()=>{
+    const { t } = useTranslation("errors");
+    return <div>
+          {t([
+        `payment.${code}`,
+        "generic"
+    ])}
+          {t([
+        "payment.declined",
+        "missing"
+    ])}
+          <Trans i18nKey={[
+        "common:foo1",
+        "common:save"
+    ]}/>
+          <Select options={[
+        l.common.foo2
+    ]}/>
+        </div>;
+};
@@ -0,0 +1 @@
+<Collapsible label={"common:Summary"} trigger={mobileHeaderContent(faChevronDown, "common:ShowOrderSummary")}/>;
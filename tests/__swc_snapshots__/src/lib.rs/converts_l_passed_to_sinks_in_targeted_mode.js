@@ -0,0 +1,9 @@
+const Component = ()=>{
+    const { t: translate } = useTranslation();
+    return <Trans i18nKey={"common:foo1"}>
+          {t("common:foo2", {
+        label: i18n.t("common:foo3")
+    })}
+          {translate(isFoo ? "common:foo4" : `common:foo5.${bar}`)}
+        </Trans>;
+};
@@ -0,0 +1,9 @@
+const Checkout = ()=>{
+    const { t } = useTranslation();
+    return <Component title={"common:foo1"}>
+          {t("common:foo2")}
+          <p>{t(`common:foo3.${bar}`)}</p>
+          {isFoo ? t("common:foo4") : t("common:foo5")}
+          {isBar && (t("common:foo6") ?? t("common:foo7"))}
+        </Component>;
+};
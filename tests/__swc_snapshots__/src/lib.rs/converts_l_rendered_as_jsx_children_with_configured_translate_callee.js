@@ -0,0 +1,2 @@
+import i18n from "i18next";
+<p>{i18n.t("common:foo")}</p>;
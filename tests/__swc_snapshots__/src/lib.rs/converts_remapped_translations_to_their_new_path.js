@@ -0,0 +1,5 @@
+t("forms:submit");
+t(`forms:fields.name.${field}`);
+t("common:format");
+t(`common:foo.${bar}`);
+t(`common:${form}.submit`);
@@ -0,0 +1,7 @@
+import checkoutL from "locales/en/checkout.json";
+import * as ordersL from "locales/en/orders.json";
+t("checkout:summary.total");
+t(`checkout:items.${count}`);
+t("common:save");
+t(ordersL.status);
+t("common:cancel");
@@ -0,0 +1,5 @@
+t(/* translation-converter-ignore */ l.common.foo1, {
+    label: "common:foo2"
+});
+t(/* translation-converter-ignore */ isFoo ? l.common.foo3 : l.common.foo4);
+<Trans i18nKey={ /* translation-converter-ignore */ l.common.foo5}>hello world</Trans>;
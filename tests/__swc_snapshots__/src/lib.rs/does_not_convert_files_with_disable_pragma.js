@@ -0,0 +1,5 @@
+// translation-converter-disable
+t(l.common.foo1);
+const testFunc = ()=>{
+    return l.common.foo2;
+};
@@ -0,0 +1,5 @@
+t("common:foo1", {
+    // translation-converter-disable-next-line
+    label: l.common.foo2,
+    title: "common:foo3"
+});
@@ -0,0 +1,8 @@
+const testFunc = ()=>{
+    // translation-converter-disable-next-line
+    const allStrings = [
+        l.common.foo1,
+        t(l.common.foo2)
+    ];
+    return t("common:foo3");
+};
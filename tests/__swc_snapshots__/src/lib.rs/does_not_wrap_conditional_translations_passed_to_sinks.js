@@ -0,0 +1,3 @@
+const label = isFoo ? i18n.t("common:foo1") : i18n.t("common:foo2");
+t(isFoo ? "common:foo3" : "common:foo4");
+<Trans i18nKey={isFoo ? "common:foo5" : "common:foo6"}/>;
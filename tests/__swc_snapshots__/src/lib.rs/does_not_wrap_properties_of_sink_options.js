@@ -0,0 +1,11 @@
+const options = {
+    label: i18n.t("common:foo1")
+};
+t("common:foo2", {
+    label: "common:foo3"
+});
+t("common:foo4", {
+    label: format({
+        value: i18n.t("common:foo5")
+    })
+});
@@ -0,0 +1,5 @@
+import { t } from "i18next";
+const label = t(`${namespace}:foo1`);
+export const namespaces = [
+    "*"
+];
@@ -0,0 +1,9 @@
+import { useTranslation } from "react-i18next";
+const Checkout = ()=>{
+    const { t } = useTranslation("checkout");
+    return <p>{t("common:foo1")}{t("foo2")}{t(`common:foo3.${bar}`)}{t(`${namespace}:foo4`)}</p>;
+};
+export const __i18nNamespaces = [
+    "common",
+    "checkout"
+];
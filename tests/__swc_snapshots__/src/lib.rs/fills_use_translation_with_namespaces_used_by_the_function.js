@@ -0,0 +1,24 @@
+function Checkout({ items }) {
+    const { t } = useTranslation([
+        "cart",
+        "common",
+        "checkout"
+    ]);
+    const Item = ()=>{
+        const { t } = useTranslation([
+            "cart"
+        ]);
+        return <p>{t("cart:foo1")}</p>;
+    };
+    return <div>
+          {t("common:foo2")}
+          {items.map(function(item) {
+        return t(`checkout:foo3.${item}`);
+    })}
+          <Item/>
+        </div>;
+}
+function Header() {
+    const { t } = useTranslation("header");
+    return <h1>{t("foo4")}</h1>;
+}
@@ -0,0 +1,17 @@
+import { l } from "./locales";
+const { common, checkout } = l;
+function format(common) {
+    return common.foo1;
+}
+function Summary() {
+    t("common:foo2");
+    if (isFoo) {
+        const checkout = getCheckout();
+        t(checkout.foo3);
+    }
+    try {
+        t("checkout:foo4");
+    } catch (common) {
+        report(common.foo5);
+    }
+}
@@ -0,0 +1,15 @@
+import { useTranslation } from "react-i18next";
+const Checkout = ()=>{
+    const { t } = useTranslation("checkout", {
+        keyPrefix: "summary"
+    });
+    const common = l.common;
+    return <div>
+          <p>{l.common.foo1}</p>
+          {t("total")}
+          {t(`errors:${code}`)}
+          {t(common.foo2)}
+          {t(/* translation-converter-ignore */ "common:legacy")}
+          <Trans i18nKey="common:welcome"/>
+        </div>;
+};
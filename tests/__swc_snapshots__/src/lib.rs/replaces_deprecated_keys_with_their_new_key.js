@@ -0,0 +1,4 @@
+t("common:submit");
+t("forms:cancel");
+t(`common:save.${bar}`);
+t("common:saved");
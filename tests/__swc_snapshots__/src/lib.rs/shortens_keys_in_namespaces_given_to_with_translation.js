@@ -0,0 +1,13 @@
+function Summary({ t }) {
+    return <p>{t("total")}</p>;
+}
+class Cart extends Component {
+    render() {
+        return <p>{this.props.t("empty")}{this.props.t("common:save")}</p>;
+    }
+}
+export const SummaryWithTranslation = withTranslation("checkout")(Summary);
+export default withTranslation([
+    "cart",
+    "common"
+])(Cart);
@@ -0,0 +1,15 @@
+const Checkout = ()=>{
+    const { t } = useTranslation([
+        "checkout",
+        "common"
+    ]);
+    const translate = i18n.getFixedT(null, "orders");
+    return <p>
+          {t("total")}
+          {t(`items.${count}`)}
+          {t("common:save")}
+          {t("orders:status")}
+          {translate("status")}
+        </p>;
+};
+const Other = ()=>t("checkout:total");
@@ -0,0 +1,16 @@
+const Summary = ()=>{
+    const { t } = useTranslation([
+        "checkout",
+        "common"
+    ], {
+        keyPrefix: "summary"
+    });
+    const translate = i18n.getFixedT(null, "orders", "status");
+    return <p>
+          {t("total")}
+          {t(`items.${count}`)}
+          {t("common:save")}
+          {t("checkout:header.title")}
+          {translate("shipped")}
+        </p>;
+};
@@ -0,0 +1,11 @@
+import i18n from "i18next";
+import React from "react";
+const getLabel = ()=>{
+    return i18n.t("common:foo1");
+};
+const Component = ()=><Trans i18nKey={"common:foo2"} title={i18n.t("common:foo3")}>
+        <Button label={i18n.t(`common:foo4.${bar}`)} options={{
+        placeholder: i18n.t("common:foo5")
+    }}/>
+        {isFoo ? i18n.t("common:foo6") : i18n.t("common:foo7")}
+      </Trans>;
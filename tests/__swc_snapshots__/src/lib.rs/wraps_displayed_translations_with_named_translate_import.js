@@ -0,0 +1,2 @@
+import { translate } from "./i18n";
+const label = isFoo ? translate("common:foo1") : translate("common:foo2");
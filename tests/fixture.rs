@@ -0,0 +1,87 @@
+use std::{
+    fs,
+    io::{self, Write},
+    path::PathBuf,
+    sync::{Arc, Mutex},
+};
+use swc_core::{
+    common::errors::{Handler, HANDLER},
+    ecma::transforms::testing::{test_fixture, FixtureTestConfig},
+};
+use swc_ecma_ast::{Pass, Program};
+use swc_ecma_parser::{Syntax, TsSyntax};
+use swc_ecma_visit::visit_mut_pass;
+use testing::NormalizedOutput;
+use translation_converter::{config::Config, TranslationConverter};
+
+/// Runs the converter over tests/fixtures/<case>/input.tsx with the options of config.json
+/// and compares the result with output.tsx and the diagnostics with output.stderr
+/// UPDATE=1 cargo test writes the output of new cases
+#[testing::fixture("tests/fixtures/**/input.tsx")]
+fn fixture(input: PathBuf) {
+    let dir = input.parent().unwrap();
+
+    // cases without a config.json use the default options
+    let config = fs::read_to_string(dir.join("config.json")).unwrap_or_else(|_| "{}".to_string());
+    let diagnostics = Diagnostics::default();
+
+    test_fixture(
+        Syntax::Typescript(TsSyntax {
+            tsx: true,
+            ..Default::default()
+        }),
+        &|t| Captured {
+            pass: visit_mut_pass(TranslationConverter::new(
+                t.comments.clone(),
+                Config::parse(&config, None),
+            )),
+            handler: Handler::with_emitter_writer(
+                Box::new(diagnostics.clone()),
+                Some(t.cm.clone()),
+            ),
+        },
+        &input,
+        &dir.join("output.tsx"),
+        FixtureTestConfig {
+            module: Some(true),
+            ..Default::default()
+        },
+    );
+
+    // a missing output.stderr expects no diagnostics
+    NormalizedOutput::from(diagnostics.output())
+        .compare_to_file(dir.join("output.stderr"))
+        .unwrap();
+}
+
+/// Everything the converter emits through the SWC handler, warnings included
+#[derive(Clone, Default)]
+struct Diagnostics(Arc<Mutex<Vec<u8>>>);
+
+impl Diagnostics {
+    fn output(&self) -> String {
+        String::from_utf8_lossy(&self.0.lock().unwrap()).to_string()
+    }
+}
+
+impl Write for Diagnostics {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.lock().unwrap().write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Runs a pass with HANDLER capturing its diagnostics, the handler of the fixture drops warnings
+struct Captured<P: Pass> {
+    pass: P,
+    handler: Handler,
+}
+
+impl<P: Pass> Pass for Captured<P> {
+    fn process(&mut self, program: &mut Program) {
+        HANDLER.set(&self.handler, || self.pass.process(program));
+    }
+}
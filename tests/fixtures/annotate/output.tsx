@@ -0,0 +1,5 @@
+import { l } from "@fullscript/i18n";
+t("common:save" /* l.common.save */ );
+i18n.t("errors:payment.declined" /* l.errors.payment.declined */ , {
+    count
+});
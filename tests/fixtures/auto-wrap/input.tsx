@@ -0,0 +1,11 @@
+import { l } from "@fullscript/i18n";
+
+export function Actions() {
+  return (
+    <>
+      <Button label={l.common.save} />
+      <Trans i18nKey={l.common.welcome} />
+      <p>{l.common.description}</p>
+    </>
+  );
+}
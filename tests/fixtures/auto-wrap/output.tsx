@@ -0,0 +1,9 @@
+import i18n from "i18next";
+import { l } from "@fullscript/i18n";
+export function Actions() {
+    return <>
+      <Button label={i18n.t("common:save")}/>
+      <Trans i18nKey={"common:welcome"}/>
+      <p>{i18n.t("common:description")}</p>
+    </>;
+}
@@ -0,0 +1,7 @@
+import { l } from "@fullscript/i18n";
+
+export function Summary({ namespace }) {
+  const { t } = useTranslation();
+
+  return [t(l.checkout.total), t(l[namespace].title)];
+}
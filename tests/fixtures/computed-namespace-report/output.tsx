@@ -0,0 +1,11 @@
+import { l } from "@fullscript/i18n";
+export function Summary({ namespace }) {
+    const { t } = useTranslation();
+    return [
+        t("checkout:total"),
+        t(`${namespace}:title`)
+    ];
+}
+export const __i18nNamespaces = [
+    "checkout"
+];
@@ -0,0 +1,13 @@
+import { useTranslation } from "react-i18next";
+import { l } from "@fullscript/i18n";
+
+export function Checkout() {
+  const { t } = useTranslation();
+
+  return (
+    <div>
+      <h1>{t(l.checkout.title)}</h1>
+      <p>{t(l.checkout.summary[step].description)}</p>
+    </div>
+  );
+}
@@ -0,0 +1,9 @@
+import { useTranslation } from "react-i18next";
+import { l } from "@fullscript/i18n";
+export function Checkout() {
+    const { t } = useTranslation();
+    return <div>
+      <h1>{t("checkout:title")}</h1>
+      <p>{t(`checkout:summary.${step}.description`)}</p>
+    </div>;
+}
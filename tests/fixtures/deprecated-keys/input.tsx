@@ -0,0 +1,5 @@
+import { l } from "@fullscript/i18n";
+
+t(l.common.save);
+t(l.common.legacy);
+t(l.common.save[bar]);
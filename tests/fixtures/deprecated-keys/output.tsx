@@ -0,0 +1,4 @@
+import { l } from "@fullscript/i18n";
+t("common:submit");
+t("common:legacy");
+t(`common:save.${bar}`);
@@ -0,0 +1,3 @@
+import { l } from "@fullscript/i18n";
+
+t([l.errors.specific, l.errors.generic]);
@@ -0,0 +1,5 @@
+import { l } from "@fullscript/i18n";
+t([
+    "errors:specific",
+    "errors:generic"
+]);
@@ -0,0 +1,12 @@
+import { l } from "@fullscript/i18n";
+
+export function Summary() {
+  const { t } = useTranslation();
+
+  return (
+    <p>
+      {l.checkout.total}
+      {t(l.checkout.tax)}
+    </p>
+  );
+}
@@ -0,0 +1,7 @@
+import { l } from "@fullscript/i18n";
+
+export function Summary() {
+  const { t } = useTranslation("checkout", { keyPrefix: "summary" });
+
+  return [t(l.checkout.summary.total), t(l.checkout.items)];
+}
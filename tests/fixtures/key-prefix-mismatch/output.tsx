@@ -0,0 +1,10 @@
+import { l } from "@fullscript/i18n";
+export function Summary() {
+    const { t } = useTranslation("checkout", {
+        keyPrefix: "summary"
+    });
+    return [
+        t("total"),
+        t("checkout:items")
+    ];
+}
@@ -0,0 +1,13 @@
+import { Trans, useTranslation } from "react-i18next";
+export function Summary() {
+    const { t } = useTranslation("checkout", {
+        keyPrefix: "summary"
+    });
+    return <>
+      {t("total")}
+      {t(`errors:${code}`)}
+      {t("common:foo-bar")}
+      {t(/* translation-converter-ignore */ "common:legacy")}
+      <Trans i18nKey="common:welcome"/>
+    </>;
+}
@@ -0,0 +1,11 @@
+import { l } from "@fullscript/i18n";
+
+export function Payment({ code }) {
+  const { t } = useTranslation("errors");
+
+  return [
+    t([l.errors.payment[code], l.errors.generic]),
+    t([l.errors.payment[code], l.errors.unknown]),
+    t([l.errors.generic, l.errors.payment]),
+  ];
+}
@@ -0,0 +1,18 @@
+import { l } from "@fullscript/i18n";
+export function Payment({ code }) {
+    const { t } = useTranslation("errors");
+    return [
+        t([
+            `payment.${code}`,
+            "generic"
+        ]),
+        t([
+            `payment.${code}`,
+            "unknown"
+        ]),
+        t([
+            "generic",
+            "payment"
+        ])
+    ];
+}
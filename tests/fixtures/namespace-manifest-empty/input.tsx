@@ -0,0 +1,3 @@
+export function Summary({ total }) {
+  return <p>{total}</p>;
+}
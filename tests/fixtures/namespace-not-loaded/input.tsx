@@ -0,0 +1,7 @@
+import { l } from "@fullscript/i18n";
+
+export function Summary() {
+  const { t } = useTranslation(["checkout", "common"]);
+
+  return [t(l.checkout.total), t(l.common.save), t(l.cart.empty)];
+}
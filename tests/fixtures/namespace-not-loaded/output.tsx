@@ -0,0 +1,12 @@
+import { l } from "@fullscript/i18n";
+export function Summary() {
+    const { t } = useTranslation([
+        "checkout",
+        "common"
+    ]);
+    return [
+        t("total"),
+        t("common:save"),
+        t("cart:empty")
+    ];
+}
@@ -0,0 +1,4 @@
+import { l } from "@fullscript/i18n";
+
+t(l.common.form.submit);
+t(l.common.form.legacy.name);
@@ -0,0 +1,3 @@
+import { l } from "@fullscript/i18n";
+t("forms:submit");
+t("forms:fields.name");
@@ -0,0 +1,4 @@
+import checkoutL from "locales/en/checkout.json";
+
+t(checkoutL.summary.total);
+t(commonL.save);
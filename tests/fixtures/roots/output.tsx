@@ -0,0 +1,3 @@
+import checkoutL from "locales/en/checkout.json";
+t("checkout:summary.total");
+t("common:save");
@@ -0,0 +1,4 @@
+import { l } from "@fullscript/i18n";
+
+t(l.common.save);
+mobileHeaderContent(faChevronDown, l.common.showOrderSummary);
@@ -0,0 +1,3 @@
+import { l } from "@fullscript/i18n";
+t("common:save");
+mobileHeaderContent(faChevronDown, l.common.showOrderSummary);
@@ -0,0 +1,14 @@
+import { Trans, useTranslation } from "react-i18next";
+import { l } from "@fullscript/i18n";
+
+export function Summary() {
+  const { t: translate } = useTranslation();
+
+  return (
+    <>
+      <Trans i18nKey={l.common.welcome} />
+      {translate(l.checkout.total)}
+      {mobileHeaderContent(faChevronDown, l.common.showOrderSummary)}
+    </>
+  );
+}
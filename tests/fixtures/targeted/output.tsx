@@ -0,0 +1,10 @@
+import { Trans, useTranslation } from "react-i18next";
+import { l } from "@fullscript/i18n";
+export function Summary() {
+    const { t: translate } = useTranslation();
+    return <>
+      <Trans i18nKey={"common:welcome"}/>
+      {translate("checkout:total")}
+      {mobileHeaderContent(faChevronDown, "common:showOrderSummary")}
+    </>;
+}
@@ -0,0 +1,13 @@
+import { l } from "@fullscript/i18n";
+
+export const Summary = ({ t }) => <p>{l.checkout.total}</p>;
+
+export function Cart(props) {
+  const { t } = props;
+
+  return <p>{l.cart.empty}</p>;
+}
+
+export function Item(t) {
+  return <p>{l.cart.item}</p>;
+}
@@ -0,0 +1,11 @@
+import { l } from "@fullscript/i18n";
+
+export function Summary() {
+  return <p>{l.checkout.total}</p>;
+}
+
+export function Cart() {
+  const { t } = useTranslation();
+
+  return <p>{l.cart.empty}</p>;
+}
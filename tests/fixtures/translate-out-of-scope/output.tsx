@@ -0,0 +1,8 @@
+import { l } from "@fullscript/i18n";
+export function Summary() {
+    return <p>{t("checkout:total")}</p>;
+}
+export function Cart() {
+    const { t } = useTranslation();
+    return <p>{t("cart:empty")}</p>;
+}
@@ -0,0 +1,5 @@
+"use client";
+
+import { l } from "@fullscript/i18n";
+
+export const Summary = () => <p>{l.checkout.total}</p>;
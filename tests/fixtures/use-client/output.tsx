@@ -0,0 +1,4 @@
+"use client";
+import i18n from "i18next";
+import { l } from "@fullscript/i18n";
+export const Summary = ()=><p>{i18n.t("checkout:total")}</p>;